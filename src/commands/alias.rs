@@ -8,6 +8,7 @@ use crate::{
 };
 use futures_util::{future, stream, Stream, StreamExt};
 use poise::serenity_prelude::CreateEmbedFooter;
+use walzecore::dice::system::{self, bands::Tier};
 
 #[allow(clippy::unused_async)]
 #[poise::command(
@@ -107,7 +108,9 @@ pub async fn dump_alias(ctx: Context<'_>) -> Result<()> {
         "namespace_switch",
         "namespace_new",
         "namespace_dump",
-        "namespace_delete"
+        "namespace_delete",
+        "namespace_system",
+        "namespace_tier"
     )
 )]
 pub async fn namespace(_: Context<'_>) -> Result<()> {
@@ -204,6 +207,85 @@ pub async fn namespace_delete(
     Ok(())
 }
 
+/// bind the current namespace to a game system, or show the available systems
+#[poise::command(slash_command, rename = "system")]
+pub async fn namespace_system(
+    ctx: Context<'_>,
+    #[description = "Game system to read rolls of this namespace with"]
+    #[autocomplete = "autocomplete_system"]
+    system: Option<String>,
+) -> Result<()> {
+    let Some(name) = system else {
+        let systems = system::systems()
+            .iter()
+            .map(|system| format!("`{}` - {}", system.name(), system.description()))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let reply = reply!(ctx, "Game Systems", systems, EmbedColor::Ok).ephemeral(true);
+        ctx.send(reply).await?;
+        return Ok(());
+    };
+
+    let system = system::by_name(&name)?;
+    let mut user = ctx.data().lock().await;
+    let user = user.get_or_create(ctx.author().id);
+    user.system_mut(Some(system.name()));
+
+    let desc = format!(
+        "rolls in {} are read as {} and can use its syntax",
+        user.namespace(),
+        system.name()
+    );
+    let reply = reply!(ctx, "Bound game system", desc, EmbedColor::Ok).ephemeral(true);
+    ctx.send(reply).await?;
+    Ok(())
+}
+
+/// describe what an outcome tier means in the current namespace
+#[poise::command(slash_command, rename = "tier")]
+pub async fn namespace_tier(
+    ctx: Context<'_>,
+    #[description = "Outcome tier to describe"]
+    #[autocomplete = "autocomplete_tier"]
+    tier: String,
+    #[description = "What the tier means. Leave empty for the default"] description: Option<String>,
+) -> Result<()> {
+    let tier: Tier = tier.parse()?;
+    let mut user = ctx.data().lock().await;
+    let user = user.get_or_create(ctx.author().id);
+    user.tier_description_mut(tier.name(), description);
+
+    let desc = format!(
+        "{tier} in {} -> {}",
+        user.namespace(),
+        user.tier_description(tier.name())
+            .unwrap_or(tier.default_description())
+    );
+    let reply = reply!(ctx, "Described outcome tier", desc, EmbedColor::Ok).ephemeral(true);
+    ctx.send(reply).await?;
+    Ok(())
+}
+
+async fn autocomplete_tier<'a>(
+    _ctx: Context<'_>,
+    partial: &'a str,
+) -> impl Stream<Item = String> + 'a {
+    let partial = partial.to_lowercase();
+    stream::iter(Tier::ALL)
+        .map(|tier| tier.name().to_string())
+        .filter(move |name| future::ready(name.contains(&partial)))
+}
+
+async fn autocomplete_system<'a>(
+    _ctx: Context<'_>,
+    partial: &'a str,
+) -> impl Stream<Item = String> + 'a {
+    let partial = partial.to_lowercase();
+    stream::iter(system::systems())
+        .map(|system| system.name().to_string())
+        .filter(move |name| future::ready(name.contains(&partial)))
+}
+
 async fn autocomplete_namespace<'a>(
     ctx: Context<'_>,
     partial: &'a str,
@@ -0,0 +1,25 @@
+use crate::{commands::eval, Context, Result};
+
+use walzecore::dice::system::{Blades, Pbta};
+
+/// roll a Blades in the Dark action and read its outcome tier
+#[poise::command(slash_command)]
+pub async fn blades(
+    ctx: Context<'_>,
+    #[description = "Number of dice in the pool, 0 rolls two and takes the lowest"] dice: u64,
+    #[description = "What the roll is for"] label: Option<String>,
+) -> Result<()> {
+    let expr = format!("blades({dice})");
+    eval::send_labeled_roll(ctx, &expr, label, Some(&Blades)).await
+}
+
+/// roll a Powered by the Apocalypse move, 2d6 plus a stat, and read its outcome tier
+#[poise::command(slash_command)]
+pub async fn pbta(
+    ctx: Context<'_>,
+    #[description = "Stat added to the roll"] stat: i64,
+    #[description = "What the roll is for"] label: Option<String>,
+) -> Result<()> {
+    let expr = format!("pbta({stat:+})");
+    eval::send_labeled_roll(ctx, &expr, label, Some(&Pbta)).await
+}
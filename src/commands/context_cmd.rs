@@ -1,6 +1,11 @@
-use crate::{Context, Result};
+use crate::{
+    commands::{eval, tz},
+    utils::macros::discord::reply_error,
+    Context, Result,
+};
 
 use poise::serenity_prelude as serenity;
+use walzecore::dice::expr::find_rolls;
 
 /// repeats the selected message
 #[poise::command(context_menu_command = "Echo", slash_command)]
@@ -9,6 +14,42 @@ pub async fn echo(ctx: Context<'_>, msg: serenity::Message) -> Result<()> {
     Ok(())
 }
 
+/// rolls every dice expression and alias found in the selected message
+#[poise::command(context_menu_command = "Roll dice here")]
+pub async fn roll_here(ctx: Context<'_>, msg: serenity::Message) -> Result<()> {
+    let aliases: Vec<String> = {
+        let mut users = ctx.data().lock().await;
+        let user = users.get_or_create(ctx.author().id);
+        user.aliases()?
+            .into_iter()
+            .map(|(alias, _)| alias.to_string())
+            .collect()
+    };
+    let aliases: Vec<&str> = aliases.iter().map(String::as_str).collect();
+
+    let rolls = find_rolls(&msg.content, &aliases);
+    if rolls.is_empty() {
+        ctx.send(reply_error!(
+            ctx,
+            "No dice found",
+            "The message contains no dice expressions or aliases"
+        ))
+        .await?;
+        return Ok(());
+    }
+
+    let expr = rolls
+        .iter()
+        .map(|(label, roll)| match label {
+            Some(label) => format!("{label}: {roll}"),
+            None => (*roll).to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let resolved_expr = eval::resolve_aliases(ctx, expr).await?;
+    eval::send_roll(ctx, &resolved_expr).await
+}
+
 /// show help menu
 #[poise::command(slash_command)]
 pub async fn help(
@@ -19,3 +60,9 @@ pub async fn help(
     poise::builtins::help(ctx, command.as_deref(), config).await?;
     Ok(())
 }
+
+/// shows the current local time of the selected user, if they shared their home timezone
+#[poise::command(context_menu_command = "Local time")]
+pub async fn local_time(ctx: Context<'_>, user: serenity::User) -> Result<()> {
+    tz::local_time(ctx, &user).await
+}
@@ -1,12 +1,31 @@
+use std::time::{Duration, Instant};
+
 use crate::{
+    models,
     utils::{
         self,
-        macros::{discord::embed, EmbedColor},
+        layout::{self, layout},
+        macros::discord::embed_error,
     },
-    Context, Result,
+    Context, Data, Result,
+};
+
+use poise::serenity_prelude::{
+    ButtonStyle, ComponentInteractionCollector, CreateActionRow, CreateAttachment, CreateButton,
+    CreateEmbed, CreateEmbedAuthor, CreateEmbedFooter, CreateInteractionResponse,
+    CreateInteractionResponseMessage, UserId,
+};
+use walzecore::dice::{
+    expr::{self, Advantage},
+    system::{self, bands::Tier},
+    DiceRoll, GameSystem, RollId, RollRecord,
 };
 
-use caith::Roller;
+/// how long the reroll buttons stay active after a roll
+///
+/// the buttons are removed with the interaction token of the command, which expires after
+/// 15 minutes, so this has to end well before that
+const BUTTON_TIMEOUT: Duration = Duration::from_secs(600);
 
 /// evaluate a dice string and return the result
 #[poise::command(slash_command)]
@@ -15,35 +34,260 @@ pub async fn eval(
     #[description = "Evaluate this dice expression"] expr: String,
     #[description = "Show the dice roll in chat"] show: Option<bool>,
 ) -> Result<()> {
-    let data = ctx.data();
+    let ephemeral = show.unwrap_or(false);
+    let resolved_expr = resolve_aliases(ctx, expr).await?;
+
+    let (embed, attachment) = roll_reply(ctx, &resolved_expr).await?;
+
+    let ctx_id = ctx.id();
+    let reroll_id = format!("{ctx_id}reroll");
+    let adv_id = format!("{ctx_id}adv");
+    let disadv_id = format!("{ctx_id}disadv");
+
+    let mut buttons = vec![CreateButton::new(&reroll_id)
+        .label("Reroll")
+        .style(ButtonStyle::Primary)];
+    if expr::has_d20(&resolved_expr) {
+        buttons.push(
+            CreateButton::new(&adv_id)
+                .label("Roll with Advantage")
+                .style(ButtonStyle::Success),
+        );
+        buttons.push(
+            CreateButton::new(&disadv_id)
+                .label("Roll with Disadvantage")
+                .style(ButtonStyle::Danger),
+        );
+    }
+
+    let mut reply = poise::CreateReply::default()
+        .embed(embed.clone())
+        .components(vec![CreateActionRow::Buttons(buttons)])
+        .ephemeral(ephemeral);
+    if let Some(attachment) = attachment {
+        reply = reply.attachment(attachment);
+    }
+
+    let handle = ctx.send(reply).await?;
+    let message_id = handle.message().await?.id;
+
+    // presses do not extend the time the buttons stay active
+    let deadline = Instant::now() + BUTTON_TIMEOUT;
+    while let Some(press) = ComponentInteractionCollector::new(ctx.serenity_context())
+        .message_id(message_id)
+        .timeout(deadline.saturating_duration_since(Instant::now()))
+        .await
+    {
+        if press.user.id != ctx.author().id {
+            let msg = CreateInteractionResponseMessage::new()
+                .content("Only the original roller can use these buttons.")
+                .ephemeral(true);
+            press
+                .create_response(
+                    ctx.serenity_context(),
+                    CreateInteractionResponse::Message(msg),
+                )
+                .await?;
+            continue;
+        }
+
+        let expr = match press.data.custom_id.as_str() {
+            id if id == adv_id => expr::with_advantage(&resolved_expr, Advantage::Advantage),
+            id if id == disadv_id => expr::with_advantage(&resolved_expr, Advantage::Disadvantage),
+            _ => resolved_expr.clone(),
+        };
+
+        let msg = match roll_reply(ctx, &expr).await {
+            Ok((embed, attachment)) => {
+                let mut msg = CreateInteractionResponseMessage::new()
+                    .embed(embed)
+                    .ephemeral(ephemeral);
+                if let Some(attachment) = attachment {
+                    msg = msg.add_file(attachment);
+                }
+                msg
+            }
+            Err(e) => CreateInteractionResponseMessage::new()
+                .embed(embed_error!(ctx, "Error", e.to_string()))
+                .ephemeral(true),
+        };
+        press
+            .create_response(
+                ctx.serenity_context(),
+                CreateInteractionResponse::Message(msg),
+            )
+            .await?;
+    }
+
+    // remove the buttons once they time out
+    let reply = poise::CreateReply::default()
+        .embed(embed)
+        .components(vec![]);
+    handle.edit(ctx, reply).await?;
+
+    Ok(())
+}
+
+/// replaces the aliases of the author's current namespace in the expression
+pub(crate) async fn resolve_aliases(ctx: Context<'_>, expr: String) -> Result<String> {
+    resolve_user_aliases(ctx.data(), ctx.author().id, expr).await
+}
 
-    let mut user = data.lock().await;
-    let aliases = user.get_or_create(ctx.author().id).aliases()?;
+/// replaces the aliases of the user's current namespace in the expression
+pub(crate) async fn resolve_user_aliases(
+    data: &Data,
+    user: UserId,
+    expr: String,
+) -> Result<String> {
+    let mut users = data.lock().await;
+    let aliases = users.get_or_create(user).aliases()?;
 
-    let resolved_expr = aliases
+    Ok(aliases
         .iter()
-        .fold(expr, |acc, (alias, value)| acc.replace(alias, value));
+        .fold(expr, |acc, (alias, value)| acc.replace(alias, value)))
+}
+
+/// rolls a resolved expression with the given game system, or the one of the user's current
+/// namespace, under a fresh roll id and records it in the ledger
+pub(crate) async fn roll_recorded(
+    data: &Data,
+    user: UserId,
+    resolved_expr: &str,
+    system: Option<&'static dyn GameSystem>,
+) -> Result<(RollId, Vec<DiceRoll>)> {
+    let (id, record, rolls) = roll_unrecorded(data, user, resolved_expr, system).await?;
+    data.ledger().lock().await.record(id, record);
+    Ok((id, rolls))
+}
+
+/// rolls a resolved expression like [`roll_recorded`], returning the record for the ledger
+/// instead of recording it, so a secret roll cannot be verified before it is revealed
+///
+/// outcome tiers are described the way the namespace describes them after the record is made,
+/// so the descriptions do not affect verifying the roll
+pub(crate) async fn roll_unrecorded(
+    data: &Data,
+    user: UserId,
+    resolved_expr: &str,
+    system: Option<&'static dyn GameSystem>,
+) -> Result<(RollId, RollRecord, Vec<DiceRoll>)> {
+    let (system, descriptions) = {
+        let mut users = data.lock().await;
+        let user = users.get_or_create(user);
+        let system = match (system, user.system()) {
+            (Some(system), _) => system,
+            (None, Some(name)) => system::by_name(name)?,
+            (None, None) => system::default_system(),
+        };
+        let descriptions: Vec<(Tier, String)> = Tier::ALL
+            .into_iter()
+            .map(|tier| {
+                let description = user
+                    .tier_description(tier.name())
+                    .unwrap_or(tier.default_description());
+                (tier, description.to_string())
+            })
+            .collect();
+        (system, descriptions)
+    };
+
+    let (id, rng) = {
+        let mut ledger = data.ledger().lock().await;
+        if ledger.reserve() {
+            // the reserved ids have to be on disk before any of them is used
+            models::write_file("rolls.json", &ledger.to_json()).await?;
+        }
+        ledger.issue()
+    };
+    let mut rolls = utils::roll_dice_guarded(resolved_expr, system, rng, data.limits()).await?;
 
-    let die = utils::split_dice(&resolved_expr);
-    let mut embeds = Vec::with_capacity(die.len());
+    let record = RollRecord {
+        expr: resolved_expr.to_string(),
+        results: rolls.iter().map(DiceRoll::description).collect(),
+        system: Some(system.name().to_string()),
+    };
 
-    for roll in die {
-        let roller = Roller::new(roll)?;
-        let result = roller
-            .roll()
-            .map_err(|e| format!("error while parsing input: {roll}\n```\n{e}\n```"))?;
+    for roll in &mut rolls {
+        let description = descriptions
+            .iter()
+            .find(|(tier, _)| Some(*tier) == roll.tier)
+            .map(|(_, description)| description);
+        if let Some(description) = description {
+            roll.result.push_str(&format!("\n> {description}"));
+        }
+    }
+
+    Ok((id, record, rolls))
+}
+
+/// rolls a resolved expression and lays the results out into an embed and an optional attachment
+pub(crate) async fn roll_reply(
+    ctx: Context<'_>,
+    resolved_expr: &str,
+) -> Result<(CreateEmbed, Option<CreateAttachment>)> {
+    let (id, rolls) = roll_recorded(ctx.data(), ctx.author().id, resolved_expr, None).await?;
+    Ok(roll_embed(ctx, id, resolved_expr, &rolls).await)
+}
 
-        let result = utils::normalize_dice_expr(result.to_string().as_ref());
-        embeds.push(embed!(ctx, roll, result, EmbedColor::Ok));
+/// lays rolls out into an embed by the author of the command and an optional attachment
+pub(crate) async fn roll_embed(
+    ctx: Context<'_>,
+    id: RollId,
+    resolved_expr: &str,
+    rolls: &[DiceRoll],
+) -> (CreateEmbed, Option<CreateAttachment>) {
+    let author_name = match ctx.guild_id() {
+        Some(guild_id) => ctx.author().nick_in(ctx, guild_id).await,
+        None => None,
     }
+    .unwrap_or_else(|| ctx.author().name.clone());
+    let icon_url = ctx.author().static_avatar_url().unwrap_or_default();
+    let author = CreateEmbedAuthor::new(author_name).icon_url(icon_url);
+    rolls_embed(author, id, resolved_expr, rolls)
+}
+
+/// lays rolls out into an embed by `author` and an optional attachment
+pub(crate) fn rolls_embed(
+    author: CreateEmbedAuthor,
+    id: RollId,
+    resolved_expr: &str,
+    rolls: &[DiceRoll],
+) -> (CreateEmbed, Option<CreateAttachment>) {
+    let footer = format!("roll id: {id}");
+    let embed = CreateEmbed::new()
+        .author(author)
+        .color(layout::color(rolls))
+        .footer(CreateEmbedFooter::new(&footer));
+    layout(resolved_expr, rolls, &footer).apply(embed)
+}
 
-    let reply = embeds
-        .into_iter()
-        .fold(poise::CreateReply::default(), |reply, embed| {
-            reply.embed(embed)
-        })
-        .ephemeral(show.unwrap_or(false));
+/// rolls a resolved expression and sends the result to the channel
+pub(crate) async fn send_roll(ctx: Context<'_>, resolved_expr: &str) -> Result<()> {
+    send_labeled_roll(ctx, resolved_expr, None, None).await
+}
 
+/// rolls a resolved expression and sends the result to the channel under the label
+///
+/// the label is set on the rolls instead of being written into the expression,
+/// where a comma in it would split the expression into further parts. the commands of a game
+/// system roll with it whatever the namespace is bound to
+pub(crate) async fn send_labeled_roll(
+    ctx: Context<'_>,
+    resolved_expr: &str,
+    label: Option<String>,
+    system: Option<&'static dyn GameSystem>,
+) -> Result<()> {
+    let (id, mut rolls) = roll_recorded(ctx.data(), ctx.author().id, resolved_expr, system).await?;
+    if let Some(label) = label {
+        for roll in &mut rolls {
+            roll.label = Some(label.clone());
+        }
+    }
+    let (embed, attachment) = roll_embed(ctx, id, resolved_expr, &rolls).await;
+    let mut reply = poise::CreateReply::default().embed(embed);
+    if let Some(attachment) = attachment {
+        reply = reply.attachment(attachment);
+    }
     ctx.send(reply).await?;
     Ok(())
 }
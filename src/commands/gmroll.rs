@@ -0,0 +1,199 @@
+use std::time::{Duration, Instant};
+
+use crate::{
+    commands::eval,
+    utils::macros::{
+        discord::{reply, reply_error},
+        EmbedColor,
+    },
+    Context, Result,
+};
+
+use poise::serenity_prelude::{
+    self as serenity, ButtonStyle, ComponentInteractionCollector, CreateActionRow, CreateButton,
+    CreateInteractionResponse, CreateInteractionResponseMessage, CreateMessage, RoleId,
+};
+use tracing::warn;
+
+/// how long a secret roll can be revealed after it was made
+///
+/// the button is removed with the interaction token of the command, which expires after
+/// 15 minutes, so this has to end well before that
+const REVEAL_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// roll dice in secret, sending the result only to the game masters
+#[poise::command(slash_command, guild_only)]
+pub async fn gmroll(
+    ctx: Context<'_>,
+    #[description = "Evaluate this dice expression in secret"] expr: String,
+    #[description = "Hide the result from yourself as well"] blind: Option<bool>,
+) -> Result<()> {
+    let Some(guild_id) = ctx.guild_id() else {
+        return Err("secret rolls can only be made in a server".into());
+    };
+    let gm_role = ctx
+        .data()
+        .guilds()
+        .lock()
+        .await
+        .get_or_create(guild_id)
+        .gm_role()
+        .map(RoleId::new);
+    let Some(gm_role) = gm_role else {
+        ctx.send(reply_error!(
+            ctx,
+            "No GM role",
+            "An admin has to set the GM role with `/gmrole` first"
+        ))
+        .await?;
+        return Ok(());
+    };
+
+    let show_roller = ctx
+        .author_member()
+        .await
+        .is_some_and(|member| member.roles.contains(&gm_role))
+        || !blind.unwrap_or(false);
+
+    // the GMs come from the cached members, which are all members of the server with the
+    // privileged members intent (WALZE_GUILD_MEMBERS) and otherwise only those the bot has seen.
+    // listing the members over http needs the same intent
+    let (guild_name, gms) = {
+        let Some(guild) = ctx.guild() else {
+            return Err("the server is not cached yet, try again in a moment".into());
+        };
+        let gms: Vec<serenity::User> = guild
+            .members
+            .values()
+            .filter(|member| member.roles.contains(&gm_role))
+            .map(|member| member.user.clone())
+            .collect();
+        (guild.name.clone(), gms)
+    };
+    if gms.is_empty() {
+        let desc = if ctx.data().members_intent() {
+            "None of the members with the GM role could be found"
+        } else {
+            "None of the members with the GM role could be found. Without the members intent \
+             only members the bot has seen recently, e.g. in voice, can be found"
+        };
+        ctx.send(reply_error!(ctx, "No GM found", desc)).await?;
+        return Ok(());
+    }
+
+    let resolved_expr = eval::resolve_aliases(ctx, expr).await?;
+    // the roll only goes into the ledger once it is revealed, so /verify cannot expose it
+    let (id, record, rolls) =
+        eval::roll_unrecorded(ctx.data(), ctx.author().id, &resolved_expr, None).await?;
+    let (embed, attachment) = eval::roll_embed(ctx, id, &resolved_expr, &rolls).await;
+
+    let reveal_id = format!("{}reveal", ctx.id());
+    let notice = format!("a secret roll was made by {}", ctx.author().name);
+    let button = CreateButton::new(&reveal_id)
+        .label("Reveal")
+        .style(ButtonStyle::Secondary);
+    let reply = reply!(ctx, "Secret Roll", notice.clone(), EmbedColor::Ok)
+        .components(vec![CreateActionRow::Buttons(vec![button])]);
+    let handle = ctx.send(reply).await?;
+    let message_id = handle.message().await?.id;
+
+    if show_roller {
+        let mut reply = poise::CreateReply::default()
+            .embed(embed.clone())
+            .ephemeral(true);
+        if let Some(attachment) = &attachment {
+            reply = reply.attachment(attachment.clone());
+        }
+        ctx.send(reply).await?;
+    }
+
+    let gms = gms
+        .iter()
+        .filter(|gm| !(show_roller && gm.id == ctx.author().id));
+    for gm in gms {
+        let mut dm = CreateMessage::new()
+            .content(format!(
+                "Secret roll by {} in {guild_name}",
+                ctx.author().name
+            ))
+            .embed(embed.clone());
+        if let Some(attachment) = &attachment {
+            dm = dm.add_file(attachment.clone());
+        }
+        if let Err(e) = gm.direct_message(ctx.http(), dm).await {
+            warn!("failed to deliver secret roll to {}: {e}", gm.name);
+        }
+    }
+
+    // presses do not extend the time the button stays active
+    let deadline = Instant::now() + REVEAL_TIMEOUT;
+    while let Some(press) = ComponentInteractionCollector::new(ctx.serenity_context())
+        .message_id(message_id)
+        .timeout(deadline.saturating_duration_since(Instant::now()))
+        .await
+    {
+        let is_gm = press
+            .member
+            .as_ref()
+            .is_some_and(|member| member.roles.contains(&gm_role));
+        if press.user.id != ctx.author().id && !is_gm {
+            let msg = CreateInteractionResponseMessage::new()
+                .content("Only the roller or a GM can reveal this roll.")
+                .ephemeral(true);
+            press
+                .create_response(
+                    ctx.serenity_context(),
+                    CreateInteractionResponse::Message(msg),
+                )
+                .await?;
+            continue;
+        }
+
+        ctx.data().ledger().lock().await.record(id, record);
+
+        let mut msg = CreateInteractionResponseMessage::new()
+            .embed(embed)
+            .components(vec![]);
+        if let Some(attachment) = attachment {
+            msg = msg.add_file(attachment);
+        }
+        press
+            .create_response(
+                ctx.serenity_context(),
+                CreateInteractionResponse::UpdateMessage(msg),
+            )
+            .await?;
+        return Ok(());
+    }
+
+    // remove the reveal button once it times out
+    let reply = reply!(ctx, "Secret Roll", notice, EmbedColor::Ok).components(vec![]);
+    handle.edit(ctx, reply).await?;
+    Ok(())
+}
+
+/// set or clear the role whose members receive secret rolls
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn gmrole(
+    ctx: Context<'_>,
+    #[description = "Role of the game masters. Leave empty to clear"] role: Option<serenity::Role>,
+) -> Result<()> {
+    let Some(guild_id) = ctx.guild_id() else {
+        return Err("the GM role can only be set in a server".into());
+    };
+
+    ctx.data()
+        .guilds()
+        .lock()
+        .await
+        .get_or_create(guild_id)
+        .gm_role_mut(role.as_ref().map(|role| role.id.get()));
+
+    let desc = match &role {
+        Some(role) => format!("Secret rolls are sent to members of {}", role.name),
+        None => "Cleared the GM role".to_string(),
+    };
+    let reply = reply!(ctx, "GM Role", desc, EmbedColor::Ok).ephemeral(true);
+    ctx.send(reply).await?;
+    Ok(())
+}
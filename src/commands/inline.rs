@@ -0,0 +1,108 @@
+use crate::{
+    commands::eval,
+    utils::macros::{discord::reply, EmbedColor},
+    Context, Data, Result,
+};
+
+use poise::serenity_prelude::{
+    self as serenity, CreateAllowedMentions, CreateEmbed, CreateEmbedAuthor, CreateMessage,
+};
+use walzecore::{
+    db::Guild,
+    dice::{expr::inline_rolls, DiceRoll, RollId},
+};
+
+/// toggle answering [[1d20+5]] style rolls in chat messages of this server
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn inlinerolls(
+    ctx: Context<'_>,
+    #[description = "Answer [[...]] rolls in chat messages"] enabled: bool,
+) -> Result<()> {
+    let Some(guild_id) = ctx.guild_id() else {
+        return Err("inline rolls can only be toggled in a server".into());
+    };
+
+    ctx.data()
+        .guilds()
+        .lock()
+        .await
+        .get_or_create(guild_id)
+        .inline_rolls_mut(enabled);
+
+    let desc = if enabled {
+        "Rolls written as `[[1d20+5]]` in chat are now answered"
+    } else {
+        "Rolls written as `[[1d20+5]]` in chat are no longer answered"
+    };
+    let reply = reply!(ctx, "Inline Rolls", desc, EmbedColor::Ok).ephemeral(true);
+    ctx.send(reply).await?;
+    Ok(())
+}
+
+/// answers the inline rolls of a newly created chat message
+///
+/// only new messages are handled, edits are ignored, and messages of bots
+/// (including our own replies) are never answered, so a reply cannot trigger another one
+pub async fn handle_message(
+    ctx: &serenity::Context,
+    data: &Data,
+    msg: &serenity::Message,
+) -> Result<()> {
+    if msg.author.bot {
+        return Ok(());
+    }
+    let Some(guild_id) = msg.guild_id else {
+        return Ok(());
+    };
+    let enabled = data
+        .guilds()
+        .lock()
+        .await
+        .get(&guild_id)
+        .is_some_and(Guild::inline_rolls);
+    // without the message content intent the content is empty and nothing is found
+    let rolls = inline_rolls(&msg.content);
+    if !enabled || rolls.is_empty() {
+        return Ok(());
+    }
+
+    let author_name = msg
+        .author_nick(ctx)
+        .await
+        .unwrap_or_else(|| msg.author.name.clone());
+    let icon_url = msg.author.static_avatar_url().unwrap_or_default();
+    let author = CreateEmbedAuthor::new(author_name).icon_url(icon_url);
+
+    let (embed, attachment) = match roll_inline(data, msg, rolls.join(", ")).await {
+        Ok((expr, id, rolls)) => eval::rolls_embed(author, id, &expr, &rolls),
+        Err(e) => {
+            let embed = CreateEmbed::new()
+                .author(author)
+                .title("Error")
+                .description(e.to_string())
+                .color(EmbedColor::Error);
+            (embed, None)
+        }
+    };
+
+    let mut reply = CreateMessage::new()
+        .embed(embed)
+        .reference_message(msg)
+        .allowed_mentions(CreateAllowedMentions::new().replied_user(false));
+    if let Some(attachment) = attachment {
+        reply = reply.add_file(attachment);
+    }
+    msg.channel_id.send_message(ctx, reply).await?;
+    Ok(())
+}
+
+/// resolves the aliases of the joined inline rolls and rolls them
+async fn roll_inline(
+    data: &Data,
+    msg: &serenity::Message,
+    expr: String,
+) -> Result<(String, RollId, Vec<DiceRoll>)> {
+    let expr = eval::resolve_user_aliases(data, msg.author.id, expr).await?;
+    let (id, rolls) = eval::roll_recorded(data, msg.author.id, &expr, None).await?;
+    Ok((expr, id, rolls))
+}
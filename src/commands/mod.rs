@@ -1,4 +1,11 @@
 pub mod alias;
+pub mod bands;
 pub mod context_cmd;
 pub mod eval;
+pub mod gmroll;
+pub mod inline;
+pub mod percentile;
+pub mod schedule;
 pub mod tz;
+pub mod verify;
+pub mod wrath;
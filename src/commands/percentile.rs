@@ -0,0 +1,22 @@
+use crate::{commands::eval, Context, Result};
+
+use walzecore::dice::system::Coc;
+
+/// roll a Call of Cthulhu skill check with bonus or penalty dice
+#[poise::command(slash_command)]
+pub async fn coc(
+    ctx: Context<'_>,
+    #[description = "Skill value, or an alias holding it like $spot_hidden"] skill: String,
+    #[description = "Bonus dice when positive, penalty dice when negative"]
+    #[min = -2]
+    #[max = 2]
+    bonus: Option<i64>,
+    #[description = "What the roll is for"] label: Option<String>,
+) -> Result<()> {
+    let expr = match bonus {
+        Some(bonus) if bonus != 0 => format!("coc({skill}, {bonus:+})"),
+        _ => format!("coc({skill})"),
+    };
+    let resolved_expr = eval::resolve_aliases(ctx, expr).await?;
+    eval::send_labeled_roll(ctx, &resolved_expr, label, Some(&Coc)).await
+}
@@ -0,0 +1,777 @@
+use std::{
+    fmt::Write,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    commands::tz::timestamp_fields,
+    utils::macros::{
+        discord::{embed, embed_error, reply, reply_error},
+        EmbedColor,
+    },
+    Context, Result,
+};
+
+use chrono::Utc;
+use chrono_tz::Tz;
+use poise::serenity_prelude::{
+    self as serenity, ButtonStyle, ComponentInteraction, ComponentInteractionCollector,
+    CreateActionRow, CreateButton, CreateEmbed, CreateEmbedFooter, CreateInteractionResponse,
+    CreateInteractionResponseMessage, CreateMessage, EditMessage,
+};
+use tokio::sync::Mutex;
+use tracing::warn;
+use walzecore::{
+    schedule::{
+        parse_leads,
+        poll::{Poll, Vote},
+        Recurrence, Schedule, Schedules, Session,
+    },
+    tz::{
+        self,
+        dst::Fold,
+        window::{self, Window},
+    },
+};
+
+/// how often the reminder task looks for due reminders
+const REMIND_INTERVAL: Duration = Duration::from_secs(60);
+
+/// how many upcoming sessions are listed for a schedule
+const LISTED_SESSIONS: usize = 3;
+
+/// how long a poll stays open when no duration is given
+const DEFAULT_POLL_HOURS: u64 = 24;
+
+/// how many of the best slots /schedule find lists
+const LISTED_SLOTS: usize = 5;
+
+/// recurring sessions with reminders
+#[allow(clippy::unused_async)]
+#[poise::command(
+    slash_command,
+    guild_only,
+    subcommands(
+        "schedule_create",
+        "schedule_list",
+        "schedule_skip",
+        "schedule_reschedule",
+        "schedule_cancel",
+        "schedule_poll",
+        "schedule_available",
+        "schedule_find"
+    )
+)]
+pub async fn schedule(_: Context<'_>) -> Result<()> {
+    Ok(())
+}
+
+/// schedule recurring sessions in this channel
+#[poise::command(slash_command, rename = "create")]
+pub async fn schedule_create(
+    ctx: Context<'_>,
+    #[description = "Name of the campaign or session"]
+    #[max_length = 80]
+    title: String,
+    #[description = "When the first session is, e.g. \"thursday 19:00\" or \"12 Nov 19:00\""]
+    #[max_length = 60]
+    first: String,
+    #[description = "How it repeats: weekly, biweekly, monthly, \"2nd thursday\" or an RRULE"]
+    #[max_length = 100]
+    repeat: String,
+    #[description = "Timezone the sessions keep their local time in. Defaults to your home"]
+    timezone: Option<String>,
+    #[description = "When to remind before a session, e.g. \"1d, 1h\". A day and an hour by default"]
+    #[max_length = 60]
+    reminders: Option<String>,
+) -> Result<()> {
+    let Some(guild_id) = ctx.guild_id() else {
+        return Err("sessions can only be scheduled in a server".into());
+    };
+
+    let Some(zone) = author_zone(ctx, timezone).await? else {
+        return Ok(());
+    };
+    let first = match tz::stamp::parse_moment(&first, &Utc::now().with_timezone(&zone)) {
+        Ok(first) => first,
+        Err(e) => {
+            ctx.send(reply_error!(ctx, "could not parse input", e.to_string()))
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let rule = match Recurrence::parse(&repeat, first.date()) {
+        Ok(rule) => rule,
+        Err(e) => {
+            ctx.send(reply_error!(ctx, "could not parse input", e.to_string()))
+                .await?;
+            return Ok(());
+        }
+    };
+    let mut schedule = Schedule::new(
+        title,
+        guild_id.get(),
+        ctx.channel_id().get(),
+        ctx.author().id.get(),
+        zone.name(),
+        first,
+        rule,
+    );
+    if let Some(reminders) = reminders {
+        match parse_leads(&reminders) {
+            Ok(leads) => schedule.leads_mut(leads),
+            Err(e) => {
+                ctx.send(reply_error!(ctx, "could not parse input", e.to_string()))
+                    .await?;
+                return Ok(());
+            }
+        }
+    }
+
+    let desc = describe(&schedule);
+    let title = schedule.title.clone();
+    let id = ctx.data().schedules().lock().await.add(schedule);
+    ctx.data().save_schedules().await?;
+    let reply = reply!(ctx, format!("#{id} {title}"), desc, EmbedColor::Ok);
+    ctx.send(reply).await?;
+    Ok(())
+}
+
+/// list the schedules of this server
+#[poise::command(slash_command, rename = "list")]
+pub async fn schedule_list(ctx: Context<'_>) -> Result<()> {
+    let Some(guild_id) = ctx.guild_id() else {
+        return Err("schedules can only be listed in a server".into());
+    };
+
+    let desc = {
+        let schedules = ctx.data().schedules().lock().await;
+        schedules
+            .of_guild(guild_id.get())
+            .map(|(id, schedule)| format!("**#{id} {}**\n{}", schedule.title, describe(schedule)))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    };
+    if desc.is_empty() {
+        ctx.send(reply_error!(
+            ctx,
+            "No schedules",
+            "create one with `/schedule create`"
+        ))
+        .await?;
+        return Ok(());
+    }
+
+    ctx.send(reply!(ctx, "Schedules", desc, EmbedColor::Ok))
+        .await?;
+    Ok(())
+}
+
+/// cancel a single session of a schedule
+#[poise::command(slash_command, rename = "skip")]
+pub async fn schedule_skip(
+    ctx: Context<'_>,
+    #[description = "Id of the schedule, see /schedule list"] id: u64,
+    #[description = "Date of the session, e.g. \"12 Nov\" or \"2026-11-12\""]
+    #[max_length = 20]
+    date: String,
+) -> Result<()> {
+    let desc = {
+        let mut schedules = ctx.data().schedules().lock().await;
+        let schedule = organized(ctx, &mut schedules, id)?;
+        let today = Utc::now().with_timezone(&schedule.zone()).date_naive();
+        let Some(date) = tz::stamp::parse_date(&date, today) else {
+            return Err(tz::Error::DateParseError(&date).to_string().into());
+        };
+        schedule.skip(date)?;
+        format!(
+            "the session of {} is skipped\n\n{}",
+            date.format("%a %d %b"),
+            describe(schedule)
+        )
+    };
+    ctx.data().save_schedules().await?;
+
+    ctx.send(reply!(ctx, "Session skipped", desc, EmbedColor::Ok))
+        .await?;
+    Ok(())
+}
+
+/// move a single session of a schedule to another time
+#[poise::command(slash_command, rename = "reschedule")]
+pub async fn schedule_reschedule(
+    ctx: Context<'_>,
+    #[description = "Id of the schedule, see /schedule list"] id: u64,
+    #[description = "Date of the session, e.g. \"12 Nov\" or \"2026-11-12\""]
+    #[max_length = 20]
+    date: String,
+    #[description = "When it takes place instead, e.g. \"13 Nov 20:00\" or \"friday 19:30\""]
+    #[max_length = 60]
+    to: String,
+) -> Result<()> {
+    let desc = {
+        let mut schedules = ctx.data().schedules().lock().await;
+        let schedule = organized(ctx, &mut schedules, id)?;
+        let now = Utc::now().with_timezone(&schedule.zone());
+        let Some(date) = tz::stamp::parse_date(&date, now.date_naive()) else {
+            return Err(tz::Error::DateParseError(&date).to_string().into());
+        };
+        let to = tz::stamp::parse_moment(&to, &now).map_err(|e| e.to_string())?;
+        schedule.reschedule(date, to)?;
+        format!(
+            "the session of {} is moved to {}\n\n{}",
+            date.format("%a %d %b"),
+            to.format("%a %d %b %H:%M"),
+            describe(schedule)
+        )
+    };
+    ctx.data().save_schedules().await?;
+
+    ctx.send(reply!(ctx, "Session moved", desc, EmbedColor::Ok))
+        .await?;
+    Ok(())
+}
+
+/// let members vote on when a session takes place
+///
+/// polls are kept in memory only, a poll still open when the bot restarts ends without a result
+#[poise::command(slash_command, rename = "poll")]
+pub async fn schedule_poll(
+    ctx: Context<'_>,
+    #[description = "Name of the campaign or session"]
+    #[max_length = 80]
+    title: String,
+    #[description = "Up to 4 times separated by \";\", e.g. \"friday 19:00; saturday 15:00\""]
+    #[max_length = 200]
+    times: String,
+    #[description = "Timezone the times are in. Defaults to your home"] timezone: Option<String>,
+    #[description = "How many hours the poll stays open, a day by default"]
+    #[min = 1]
+    #[max = 168]
+    hours: Option<u64>,
+) -> Result<()> {
+    let Some(zone) = author_zone(ctx, timezone).await? else {
+        return Ok(());
+    };
+    let now = Utc::now().with_timezone(&zone);
+    let options = times
+        .split(';')
+        .filter(|time| !time.trim().is_empty())
+        .map(|time| {
+            tz::stamp::parse_moment(time.trim(), &now)
+                .and_then(|local| tz::dst::resolve(zone, local, Fold::default()))
+                .map(|resolved| resolved.at.with_timezone(&Utc))
+        })
+        .collect::<std::result::Result<Vec<_>, _>>();
+    let options = match options {
+        Ok(options) => options,
+        Err(e) => {
+            ctx.send(reply_error!(ctx, "could not parse input", e.to_string()))
+                .await?;
+            return Ok(());
+        }
+    };
+    let mut poll = match Poll::new(options) {
+        Ok(poll) => poll,
+        Err(e) => {
+            ctx.send(reply_error!(ctx, "could not create poll", e.to_string()))
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let open_for = Duration::from_secs(hours.unwrap_or(DEFAULT_POLL_HOURS) * 3600);
+    let deadline = Instant::now() + open_for;
+    let closes = Utc::now().timestamp() + i64::try_from(open_for.as_secs())?;
+    let base =
+        embed!(ctx, format!("Poll: {title}"), "", EmbedColor::Ok).footer(CreateEmbedFooter::new(
+            "the poll ends without a result if the bot restarts before it closes",
+        ));
+
+    let vote_prefix = format!("{}poll:", ctx.id());
+    let close_id = format!("{}close", ctx.id());
+    let mut rows: Vec<CreateActionRow> = (0..poll.options().len())
+        .map(|option| {
+            let buttons = Vote::ALL
+                .into_iter()
+                .map(|vote| {
+                    let style = match vote {
+                        Vote::Available => ButtonStyle::Success,
+                        Vote::Maybe => ButtonStyle::Secondary,
+                        Vote::No => ButtonStyle::Danger,
+                    };
+                    CreateButton::new(format!("{vote_prefix}{option}:{}", vote.name()))
+                        .label(format!("{}: {vote}", option + 1))
+                        .style(style)
+                })
+                .collect();
+            CreateActionRow::Buttons(buttons)
+        })
+        .collect();
+    rows.push(CreateActionRow::Buttons(vec![CreateButton::new(&close_id)
+        .label("Close poll")
+        .style(ButtonStyle::Primary)]));
+
+    let desc = poll_description(&poll, &[zone], Some(closes));
+    let reply = poise::CreateReply::default()
+        .embed(base.clone().description(desc))
+        .components(rows);
+    let handle = ctx.send(reply).await?;
+    let message_id = handle.message().await?.id;
+
+    // the interaction token of the command expires after 15 minutes, long before the poll
+    // closes, so the poll message is only ever updated through presses or the channel
+    while let Some(press) = ComponentInteractionCollector::new(ctx.serenity_context())
+        .message_id(message_id)
+        .timeout(deadline.saturating_duration_since(Instant::now()))
+        .await
+    {
+        if press.data.custom_id == close_id {
+            if press.user.id == ctx.author().id {
+                if let Err(e) = press
+                    .create_response(
+                        ctx.serenity_context(),
+                        CreateInteractionResponse::Acknowledge,
+                    )
+                    .await
+                {
+                    warn!("failed to acknowledge closing a poll: {e}");
+                }
+                break;
+            }
+            let msg = CreateInteractionResponseMessage::new()
+                .content("Only the organizer can close this poll.")
+                .ephemeral(true);
+            if let Err(e) = press
+                .create_response(
+                    ctx.serenity_context(),
+                    CreateInteractionResponse::Message(msg),
+                )
+                .await
+            {
+                warn!("failed to answer a poll press: {e}");
+            }
+            continue;
+        }
+
+        let vote = press
+            .data
+            .custom_id
+            .strip_prefix(&vote_prefix)
+            .and_then(|vote| vote.split_once(':'))
+            .and_then(|(option, vote)| Some((option.parse().ok()?, Vote::by_name(vote)?)));
+        let Some((option, vote)) = vote else {
+            answer_vote_error(ctx, &press, "this button does not belong to the poll").await;
+            continue;
+        };
+        if let Err(e) = poll.vote(press.user.id.get(), option, vote) {
+            answer_vote_error(ctx, &press, &e.to_string()).await;
+            continue;
+        }
+
+        let zones = voter_zones(ctx, &poll, zone).await;
+        let desc = poll_description(&poll, &zones, Some(closes));
+        let msg = CreateInteractionResponseMessage::new().embed(base.clone().description(desc));
+        if let Err(e) = press
+            .create_response(
+                ctx.serenity_context(),
+                CreateInteractionResponse::UpdateMessage(msg),
+            )
+            .await
+        {
+            warn!("failed to update a poll: {e}");
+        }
+    }
+
+    // show the final tally without the buttons once the poll is closed
+    let zones = voter_zones(ctx, &poll, zone).await;
+    let closed = base.description(poll_description(&poll, &zones, None));
+    let edit = EditMessage::new().embed(closed).components(vec![]);
+    if let Err(e) = ctx
+        .channel_id()
+        .edit_message(ctx.http(), message_id, edit)
+        .await
+    {
+        warn!("failed to close a poll: {e}");
+    }
+
+    let Some(winner) = poll.winner() else {
+        let desc = format!("nobody can make any of the times for {title}");
+        let embed = embed!(ctx, "No time found", desc, EmbedColor::Failure);
+        ctx.channel_id()
+            .send_message(ctx.http(), CreateMessage::new().embed(embed))
+            .await?;
+        return Ok(());
+    };
+    let at = poll.options()[winner];
+    let timestamp = at.timestamp();
+    let desc = format!(
+        "{title} takes place {} in {zone}\n{}",
+        at.with_timezone(&zone).format("%d/%m/%Y - %H:%M:%S"),
+        poll.tally(winner)
+    );
+    let embed = embed!(ctx, "Poll closed", desc, EmbedColor::Success);
+    let message = CreateMessage::new().embed(embed.fields(timestamp_fields(timestamp)));
+    ctx.channel_id().send_message(ctx.http(), message).await?;
+    Ok(())
+}
+
+/// answers a poll press whose vote could not be recorded with an error only the voter sees
+async fn answer_vote_error(ctx: Context<'_>, press: &ComponentInteraction, error: &str) {
+    let msg = CreateInteractionResponseMessage::new()
+        .embed(embed_error!(ctx, "Vote not recorded", error))
+        .ephemeral(true);
+    if let Err(e) = press
+        .create_response(
+            ctx.serenity_context(),
+            CreateInteractionResponse::Message(msg),
+        )
+        .await
+    {
+        warn!("failed to answer a poll press: {e}");
+    }
+}
+
+/// set the weekly times you are available, in your home timezone
+#[poise::command(slash_command, rename = "available")]
+pub async fn schedule_available(
+    ctx: Context<'_>,
+    #[description = "Weekly windows, e.g. \"mon-thu 19:00-23:00, sat 2pm-2am\". Leave empty to show yours"]
+    #[max_length = 300]
+    windows: Option<String>,
+    #[description = "Forget all your windows"] clear: Option<bool>,
+) -> Result<()> {
+    let windows = match windows.as_deref().map(Window::parse_list) {
+        Some(Ok(windows)) => Some(windows),
+        Some(Err(e)) => {
+            ctx.send(reply_error!(ctx, "could not parse input", e.to_string()))
+                .await?;
+            return Ok(());
+        }
+        None => None,
+    };
+
+    let mut users = ctx.data().lock().await;
+    let user = users.get_or_create(ctx.author().id);
+    let zone = user.home_zone().and_then(|zone| zone.parse::<Tz>().ok());
+    // only setting windows needs a home timezone, showing or clearing them does not
+    if zone.is_none() && windows.is_some() && !clear.unwrap_or(false) {
+        drop(users);
+        ctx.send(reply_error!(
+            ctx,
+            "No home timezone",
+            "windows are kept in your home timezone, set it with `/tz set-home` first"
+        ))
+        .await?;
+        return Ok(());
+    }
+    if clear.unwrap_or(false) {
+        user.availability_mut(Vec::new());
+    } else if let Some(windows) = windows {
+        user.availability_mut(windows);
+    }
+
+    let mut desc = user
+        .availability()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("\n");
+    drop(users);
+    if desc.is_empty() {
+        desc = "no windows set, `/schedule find` leaves you out".to_string();
+    } else if let Some(zone) = zone {
+        let _ = write!(desc, "\n\nin {zone}, used by `/schedule find`");
+    } else {
+        let _ = write!(
+            desc,
+            "\n\nyou have no home timezone, `/schedule find` leaves you out until you set one"
+        );
+    }
+
+    ctx.send(reply!(ctx, "Availability", desc, EmbedColor::Ok).ephemeral(true))
+        .await?;
+    Ok(())
+}
+
+/// find the times everyone in a party is available
+#[poise::command(slash_command, rename = "find")]
+pub async fn schedule_find(
+    ctx: Context<'_>,
+    #[description = "Members to find a time for, e.g. \"@ana @bo\". You are always included"]
+    #[max_length = 500]
+    party: String,
+    #[description = "How long the session lasts at least, e.g. \"3h\" or \"2h 30m\""]
+    #[max_length = 20]
+    length: String,
+    #[description = "How many weeks ahead to look, one by default"]
+    #[min = 1]
+    #[max = 4]
+    weeks: Option<u8>,
+) -> Result<()> {
+    let min = tz::natural::parse_span(&length.to_lowercase())
+        .filter(|min| *min > chrono::Duration::zero());
+    let Some(min) = min else {
+        let desc =
+            format!("could not understand the length \"{length}\", try \"3h\" or \"2h 30m\"");
+        ctx.send(reply_error!(ctx, "could not parse input", desc))
+            .await?;
+        return Ok(());
+    };
+
+    let mut members = vec![ctx.author().id];
+    for mention in party.split_inclusive('>') {
+        let Some(member) = serenity::utils::parse_user_mention(mention.trim()) else {
+            continue;
+        };
+        if !members.contains(&member) {
+            members.push(member);
+        }
+    }
+
+    let from = Utc::now();
+    let until = from + chrono::Duration::weeks(i64::from(weeks.unwrap_or(1)));
+    let (free, missing) = {
+        let users = ctx.data().lock().await;
+        let mut free = Vec::new();
+        let mut missing = Vec::new();
+        for member in &members {
+            let user = users.get(member);
+            // the zones of other members are only used if they shared them
+            let zone = user
+                .and_then(|user| {
+                    if *member == ctx.author().id {
+                        user.home_zone()
+                    } else {
+                        user.shared_zone()
+                    }
+                })
+                .and_then(|zone| zone.parse::<Tz>().ok());
+            match (user, zone) {
+                (Some(user), Some(zone)) if !user.availability().is_empty() => {
+                    free.push(window::available(user.availability(), zone, from, until));
+                }
+                _ => missing.push(format!("<@{member}>")),
+            }
+        }
+        (free, missing)
+    };
+    if !missing.is_empty() {
+        let desc = format!(
+            "{} set no windows or shared no home timezone yet, they can with \
+             `/schedule available` and `/tz set-home`",
+            missing.join(", ")
+        );
+        ctx.send(reply_error!(ctx, "Missing availability", desc))
+            .await?;
+        return Ok(());
+    }
+
+    let slots = window::overlap(&free, min);
+    let mut desc = format!(
+        "{} members, at least {}",
+        members.len(),
+        lead_text(u32::try_from(min.num_minutes()).unwrap_or(u32::MAX))
+    );
+    if slots.is_empty() {
+        let _ = write!(desc, "\n\nno time works for everyone");
+        ctx.send(reply!(ctx, "No time found", desc, EmbedColor::Failure))
+            .await?;
+        return Ok(());
+    }
+    for slot in slots.iter().take(LISTED_SLOTS) {
+        let _ = write!(
+            desc,
+            "\n<t:{}:F> to <t:{}:t> ({})",
+            slot.start.timestamp(),
+            slot.end.timestamp(),
+            lead_text(u32::try_from(slot.length().num_minutes()).unwrap_or(u32::MAX))
+        );
+    }
+
+    ctx.send(reply!(ctx, "Best times", desc, EmbedColor::Success))
+        .await?;
+    Ok(())
+}
+
+/// delete a schedule and stop its reminders
+#[poise::command(slash_command, rename = "cancel")]
+pub async fn schedule_cancel(
+    ctx: Context<'_>,
+    #[description = "Id of the schedule, see /schedule list"] id: u64,
+) -> Result<()> {
+    let title = {
+        let mut schedules = ctx.data().schedules().lock().await;
+        organized(ctx, &mut schedules, id)?;
+        schedules.remove(id)?.title
+    };
+    ctx.data().save_schedules().await?;
+
+    let desc = format!("#{id} {title} is cancelled, no more reminders are sent");
+    ctx.send(reply!(ctx, "Schedule cancelled", desc, EmbedColor::Ok))
+        .await?;
+    Ok(())
+}
+
+/// sends the reminders of every schedule as they become due
+///
+/// reminders that became due while the bot was offline are not sent
+pub async fn remind(http: Arc<serenity::Http>, schedules: Arc<Mutex<Schedules>>) {
+    let mut since = Utc::now();
+    let mut interval = tokio::time::interval(REMIND_INTERVAL);
+    loop {
+        interval.tick().await;
+        let now = Utc::now();
+        let due: Vec<_> = {
+            let schedules = schedules.lock().await;
+            schedules
+                .due(since, now)
+                .into_iter()
+                .filter_map(|reminder| {
+                    let schedule = schedules.get(reminder.id)?;
+                    Some((schedule.channel, schedule.title.clone(), reminder.session))
+                })
+                .collect()
+        };
+        since = now;
+
+        for (channel, title, session) in due {
+            let embed = CreateEmbed::new()
+                .title(title)
+                .description(session_line(&session))
+                .color(EmbedColor::Ok);
+            let message = CreateMessage::new().embed(embed);
+            if let Err(e) = serenity::ChannelId::new(channel)
+                .send_message(http.as_ref(), message)
+                .await
+            {
+                warn!("failed to send a session reminder: {e}");
+            }
+        }
+    }
+}
+
+/// resolves the given timezone, or the author's home, replying with an error if neither works
+async fn author_zone(ctx: Context<'_>, timezone: Option<String>) -> Result<Option<Tz>> {
+    let home = {
+        let users = ctx.data().lock().await;
+        users
+            .get(&ctx.author().id)
+            .and_then(|user| user.home_zone().map(String::from))
+    };
+    let Some(zone) = timezone.or(home) else {
+        ctx.send(reply_error!(
+            ctx,
+            "missing timezone",
+            "give a `timezone`, or set your home with `/tz set-home`"
+        ))
+        .await?;
+        return Ok(None);
+    };
+    match tz::zone::resolve(&zone, Utc::now()) {
+        Ok(zone) => Ok(Some(zone)),
+        Err(e) => {
+            ctx.send(reply_error!(ctx, "could not parse input", e.to_string()))
+                .await?;
+            Ok(None)
+        }
+    }
+}
+
+/// the zone of the poll and the shared home zones of everyone who voted
+async fn voter_zones(ctx: Context<'_>, poll: &Poll, origin: Tz) -> Vec<Tz> {
+    let users = ctx.data().lock().await;
+    let mut zones = vec![origin];
+    zones.extend(poll.voters().into_iter().filter_map(|voter| {
+        let zone = users.get(&serenity::UserId::new(voter))?.shared_zone()?;
+        zone.parse::<Tz>().ok()
+    }));
+    zones
+}
+
+/// lists the times of a poll with their tally, each in every zone given
+///
+/// the first zone is the one of the poll, `closes` is when an open poll closes
+fn poll_description(poll: &Poll, zones: &[Tz], closes: Option<i64>) -> String {
+    let mut desc = match closes {
+        Some(closes) => {
+            format!("vote for every time you could make, the poll closes <t:{closes}:R>")
+        }
+        None => "the poll is closed".to_string(),
+    };
+    for (option, at) in poll.options().iter().enumerate() {
+        let _ = write!(
+            desc,
+            "\n\n**{}.** <t:{}:F>\n{}",
+            option + 1,
+            at.timestamp(),
+            poll.tally(option)
+        );
+        for row in tz::convert::table(*at, zones[0], zones) {
+            let _ = write!(desc, "\n{row}");
+        }
+    }
+    desc
+}
+
+/// returns the schedule if it belongs to this server and the author organizes it
+fn organized<'s>(
+    ctx: Context<'_>,
+    schedules: &'s mut Schedules,
+    id: u64,
+) -> Result<&'s mut Schedule> {
+    let schedule = schedules.get_mut(id)?;
+    if ctx.guild_id().map(serenity::GuildId::get) != Some(schedule.guild) {
+        return Err(format!("schedule {id} not found").into());
+    }
+    if schedule.organizer != ctx.author().id.get() {
+        return Err("only the organizer can change a schedule".into());
+    }
+    Ok(schedule)
+}
+
+/// describes how a schedule repeats, its next sessions and its reminders
+fn describe(schedule: &Schedule) -> String {
+    let mut desc = format!(
+        "{} in {}, in <#{}>\n`RRULE:{}`\n",
+        schedule.rule().describe(),
+        schedule.zone(),
+        schedule.channel,
+        schedule.rule()
+    );
+    for session in schedule.upcoming(Utc::now(), LISTED_SESSIONS) {
+        let _ = write!(desc, "\n{}", session_line(&session));
+    }
+    let leads: Vec<String> = schedule
+        .leads()
+        .iter()
+        .map(|lead| lead_text(*lead))
+        .collect();
+    if !leads.is_empty() {
+        let _ = write!(desc, "\n\nreminders {} before", leads.join(", "));
+    }
+    desc
+}
+
+/// a session as discord timestamps, noting sessions that were moved
+fn session_line(session: &Session) -> String {
+    let timestamp = session.at.timestamp();
+    let mut line = format!("<t:{timestamp}:F>, <t:{timestamp}:R>");
+    if session.moved {
+        let _ = write!(line, " (moved from {})", session.date.format("%a %d %b"));
+    }
+    line
+}
+
+/// writes minutes as days, hours and minutes, `1d 2h`
+fn lead_text(minutes: u32) -> String {
+    let (days, hours, minutes) = (minutes / 1440, minutes % 1440 / 60, minutes % 60);
+    [(days, "d"), (hours, "h"), (minutes, "m")]
+        .iter()
+        .filter(|(amount, _)| *amount > 0)
+        .map(|(amount, unit)| format!("{amount}{unit}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -1,91 +1,210 @@
 use crate::{
     utils::macros::{
-        discord::{embed, embed_error, reply_error},
+        discord::{embed, embed_error, reply, reply_error},
         EmbedColor,
     },
     Context, Result,
 };
 
-use chrono::offset::TimeZone;
+use std::fmt::Write;
 
-use chrono::TimeDelta;
-use futures_util::{future, stream, Stream, StreamExt};
-use poise::serenity_prelude::{CreateActionRow, CreateButton};
+use chrono::{Duration, Utc};
+use futures_util::{stream, Stream, StreamExt};
+use poise::serenity_prelude::{self as serenity, CreateActionRow, CreateAttachment, CreateButton};
 
-use walzecore::tz;
+use chrono_tz::Tz;
+use walzecore::{
+    calendar::Event,
+    tz::{self, dst::Fold},
+};
+
+/// Discord's limit for the url of a link button.
+const MAX_LINK_LEN: usize = 512;
+
+/// which occurrence to take of a time that happens twice as the clocks go back
+#[derive(Debug, Clone, Copy, poise::ChoiceParameter)]
+pub enum DstChoice {
+    #[name = "earliest (still summer time)"]
+    Earliest,
+    #[name = "latest (after the clocks went back)"]
+    Latest,
+}
+
+impl From<DstChoice> for Fold {
+    fn from(choice: DstChoice) -> Self {
+        match choice {
+            DstChoice::Earliest => Fold::Earliest,
+            DstChoice::Latest => Fold::Latest,
+        }
+    }
+}
+
+/// timestamps and local times across timezones
+#[allow(clippy::unused_async)]
+#[poise::command(
+    slash_command,
+    rename = "tz",
+    subcommands("tzcalc", "tz_convert", "tz_set_home", "tz_for")
+)]
+pub async fn tz(_: Context<'_>) -> Result<()> {
+    Ok(())
+}
 
 /// create discord timestamp for any time
-#[poise::command(slash_command, rename = "tz")]
+#[poise::command(slash_command, rename = "stamp")]
 pub async fn tzcalc(
     ctx: Context<'_>,
-    #[description = "Timezone to convert to. Has autocomplete for locales. Check locale with timezonedb.com"]
+    #[description = "Timezone, e.g. \"Europe/Berlin\", \"Berlin\", \"CEST\" or \"UTC+5:30\". Defaults to your home"]
     #[autocomplete = "autocomplete_timezone"]
-    timezone: String,
-    #[description = "HOUR MINUTE SECOND. format: XXhYYmZZs. Counted in 24 hours"]
-    #[min_length = 4]
-    #[max_length = 9]
-    hms: String,
-    #[description = "DAY MONTH YEAR. format: DD/MM/YYYY or DD/MM/YY. Minimum valid format: 1/1/01 -> 1st January 2001"]
-    #[min_length = 6]
-    #[max_length = 10]
-    dmy: String,
-    #[description = "Title for Event to be added as Google Calendar"]
-    #[max_length = 40]
+    timezone: Option<String>,
+    #[description = "When, e.g. \"tomorrow 7pm\", \"next friday 20:30\", \"in 3 hours\" or \"2026-11-02T19:00\""]
+    #[max_length = 60]
+    when: Option<String>,
+    #[description = "Time, e.g. \"19:30\", \"7:30pm\", \"7pm\" or \"19h30m00s\""]
+    #[min_length = 3]
+    #[max_length = 12]
+    hms: Option<String>,
+    #[description = "Date, e.g. \"2026-11-02\", \"2/11/2026\", \"3 Nov 2026\" or \"3 Nov\" for the next one"]
+    #[min_length = 3]
+    #[max_length = 20]
+    dmy: Option<String>,
+    #[description = "Title of the calendar event"]
+    #[max_length = 80]
     title: Option<String>,
+    #[description = "Length of the calendar event in minutes, an hour by default"]
+    #[min = 1]
+    #[max = 10080]
+    duration: Option<u32>,
+    #[description = "Description of the calendar event"]
+    #[max_length = 300]
+    description: Option<String>,
+    #[description = "Where the event takes place, e.g. a voice channel or a VTT link"]
+    #[max_length = 100]
+    location: Option<String>,
+    #[description = "Which one to take if the time occurs twice as the clocks go back"] dst: Option<
+        DstChoice,
+    >,
 ) -> Result<()> {
-    let (timezone, date, time) = match tz::stamp::parse_tz_date_time(&timezone, &dmy, &hms) {
-        Ok(s) => s,
-        Err(e) => {
-            ctx.send(reply_error!(ctx, "could not parse input", e.to_string()))
-                .await?;
-            return Ok(());
-        }
+    let home = {
+        let users = ctx.data().lock().await;
+        users
+            .get(&ctx.author().id)
+            .and_then(|user| user.home_zone().map(String::from))
+    };
+    let Some(timezone) = timezone.or(home) else {
+        ctx.send(reply_error!(
+            ctx,
+            "missing timezone",
+            "give a `timezone`, or set your home with `/tz set-home`"
+        ))
+        .await?;
+        return Ok(());
     };
 
-    let (day, month, year) = tz::dmy(&date);
-    let (hour, min, sec) = tz::hms(&time);
+    let (timezone, local, reading) = match (&when, &hms, &dmy) {
+        (Some(when), _, _) => {
+            let timezone = match tz::zone::resolve(&timezone, Utc::now()) {
+                Ok(timezone) => timezone,
+                Err(e) => {
+                    ctx.send(reply_error!(ctx, "could not parse input", e.to_string()))
+                        .await?;
+                    return Ok(());
+                }
+            };
+            let now = Utc::now().with_timezone(&timezone);
+            match tz::natural::parse_natural(when, &now) {
+                Ok(read) => (
+                    timezone,
+                    read.local,
+                    Some(format!("\"{when}\" read as {read}")),
+                ),
+                Err(e) => {
+                    ctx.send(reply_error!(ctx, "could not parse input", e.to_string()))
+                        .await?;
+                    return Ok(());
+                }
+            }
+        }
+        (None, Some(hms), Some(dmy)) => {
+            let (timezone, date, time) = match tz::stamp::parse_tz_date_time(&timezone, dmy, hms) {
+                Ok(s) => s,
+                Err(e) => {
+                    ctx.send(reply_error!(ctx, "could not parse input", e.to_string()))
+                        .await?;
+                    return Ok(());
+                }
+            };
 
-    let dt = match timezone.with_ymd_and_hms(year, month, day, hour, min, sec) {
-        chrono::LocalResult::Single(t) => t,
-        _other => {
+            (timezone, date.and_time(time), None)
+        }
+        _ => {
             ctx.send(reply_error!(
                 ctx,
-                "Invalid date value",
-                format!("Given Date Value: {day}/{month}/{year} and {hour}/{min}/{sec}")
+                "missing time",
+                "give either `when`, or both `hms` and `dmy`"
             ))
             .await?;
             return Ok(());
         }
     };
 
+    let fold = dst.map(Fold::from).unwrap_or_default();
+    let resolved = match tz::dst::resolve(timezone, local, fold) {
+        Ok(resolved) => resolved,
+        Err(e) => {
+            ctx.send(reply_error!(ctx, "time does not exist", e.to_string()))
+                .await?;
+            return Ok(());
+        }
+    };
+    let dt = resolved.at;
+
     let timestamp = dt.timestamp();
-    let utc = dt.clone().to_utc();
-    let utc_1 = utc
-        .checked_add_signed(TimeDelta::new(3600, 0).expect("timedelta"))
-        .expect("failed to add 1hr to utc");
+    let mut event = Event::new(title.unwrap_or_else(|| "Event".to_string()), dt.to_utc());
+    if let Some(duration) = duration {
+        event.duration = Duration::minutes(i64::from(duration));
+    }
+    event.description = description;
+    event.location = location;
 
-    let title = title.unwrap_or("Event".to_string()).replace(' ', "+");
-    // YYYYMMDDTHHMMSSZ
-    let dates = format!(
-        "&dates={}T{}Z/{}T{}Z",
-        utc.format("%Y%m%d"),
-        utc.format("%H%M%S"),
-        utc_1.format("%Y%m%d"),
-        utc_1.format("%H%M%S")
-    );
-    let calendar_link = format!(
-        "https://www.google.com/calendar/render?action=TEMPLATE&text={title}&{dates}&sf=true"
-    );
-    let button = CreateButton::new_link(calendar_link).label("Add to Google Calendar");
+    let buttons = [
+        ("Google Calendar", event.google_link()),
+        ("Outlook", event.outlook_link()),
+        ("Yahoo Calendar", event.yahoo_link()),
+    ]
+    .into_iter()
+    // discord refuses longer link buttons, the attached .ics file has every detail
+    .filter(|(_, link)| link.len() <= MAX_LINK_LEN)
+    .map(|(label, link)| CreateButton::new_link(link).label(label))
+    .collect::<Vec<_>>();
+    let ics = CreateAttachment::bytes(event.to_ics(Utc::now()), "event.ics");
+    let mut desc = format!("{} in {timezone}", local.format("%d/%m/%Y - %H:%M:%S"));
+    if let Some(reading) = reading {
+        desc = format!("{desc}\n{reading}");
+    }
+    let color = match resolved.warning {
+        Some(warning) => {
+            desc = format!("{desc}\n**DST:** {warning}");
+            EmbedColor::Critical
+        }
+        None => EmbedColor::Ok,
+    };
+    let reply = embed!(ctx, format!("Generated Timestamp"), desc, color);
+    let mut reply = poise::CreateReply::default()
+        .embed(reply.fields(timestamp_fields(timestamp)))
+        .attachment(ics);
+    if !buttons.is_empty() {
+        reply = reply.components(vec![CreateActionRow::Buttons(buttons)]);
+    }
+    ctx.send(reply).await?;
+    Ok(())
+}
+
+/// the embed fields showing every discord timestamp style of a time, with the code to write it
+pub(crate) fn timestamp_fields(timestamp: i64) -> Vec<(String, String, bool)> {
     let d_ts = format!("<t:{timestamp}:");
     let d_ts_code = format!("```t:{timestamp}:");
-    let reply = embed!(
-        ctx,
-        format!("Generated Timestamp"),
-        format!("{day:0>2}/{month:0>2}/{year:0>4} - {hour:0>2}:{min:0>2}:{sec:0>2} in {timezone}"),
-        EmbedColor::Ok
-    );
-    let fields = vec![
+    vec![
         (d_ts.clone() + ":d>", d_ts_code.clone() + ":d>```", true),
         (d_ts.clone() + ":D>", d_ts_code.clone() + ":D>```", true),
         (d_ts.clone() + ":t>", d_ts_code.clone() + ":t>```", true),
@@ -93,13 +212,258 @@ pub async fn tzcalc(
         (d_ts.clone() + ":f>", d_ts_code.clone() + ":f>```", true),
         (d_ts.clone() + ":F>", d_ts_code.clone() + ":F>```", true),
         (d_ts.clone() + ":R>", d_ts_code.clone() + ":R>```", true),
-    ];
-    ctx.send(
-        poise::CreateReply::default()
-            .embed(reply.fields(fields))
-            .components(vec![CreateActionRow::Buttons(vec![button])]),
-    )
-    .await?;
+    ]
+}
+
+/// show a time in the zones of everyone in this channel, or of a list of zones
+#[poise::command(slash_command, rename = "convert")]
+pub async fn tz_convert(
+    ctx: Context<'_>,
+    #[description = "When, e.g. \"tomorrow 7pm\", \"friday 20:30\" or \"3 Nov 19:30\""]
+    #[max_length = 60]
+    time: String,
+    #[description = "Timezone the time is given in. Defaults to your home"]
+    #[autocomplete = "autocomplete_timezone"]
+    timezone: Option<String>,
+    #[description = "Comma separated zones to show. Defaults to the shared zones of this channel"]
+    #[max_length = 200]
+    zones: Option<String>,
+) -> Result<()> {
+    let mut named: Vec<(Tz, Vec<String>)> = match zones {
+        Some(zones) => {
+            let mut named = Vec::new();
+            for zone in zones.split(',').filter(|zone| !zone.trim().is_empty()) {
+                match tz::zone::resolve(zone.trim(), Utc::now()) {
+                    Ok(zone) => named.push((zone, Vec::new())),
+                    Err(e) => {
+                        ctx.send(reply_error!(ctx, "could not parse input", e.to_string()))
+                            .await?;
+                        return Ok(());
+                    }
+                }
+            }
+            named
+        }
+        None => channel_zones(ctx).await?,
+    };
+
+    let home = {
+        let users = ctx.data().lock().await;
+        users
+            .get(&ctx.author().id)
+            .and_then(|user| user.home_zone().map(String::from))
+    };
+    let Some(origin) = timezone.or(home) else {
+        ctx.send(reply_error!(
+            ctx,
+            "missing timezone",
+            "give a `timezone`, or set your home with `/tz set-home`"
+        ))
+        .await?;
+        return Ok(());
+    };
+    let origin = match tz::zone::resolve(&origin, Utc::now()) {
+        Ok(origin) => origin,
+        Err(e) => {
+            ctx.send(reply_error!(ctx, "could not parse input", e.to_string()))
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let now = Utc::now().with_timezone(&origin);
+    let resolved = match tz::stamp::parse_moment(&time, &now)
+        .and_then(|local| tz::dst::resolve(origin, local, Fold::default()))
+    {
+        Ok(resolved) => resolved,
+        Err(e) => {
+            ctx.send(reply_error!(ctx, "could not parse input", e.to_string()))
+                .await?;
+            return Ok(());
+        }
+    };
+
+    named.push((origin, Vec::new()));
+    let zones: Vec<Tz> = named.iter().map(|(zone, _)| *zone).collect();
+    let rows = tz::convert::table(resolved.at.to_utc(), origin, &zones);
+
+    let mut desc = format!(
+        "{} in {origin}, <t:{}:R>\n",
+        resolved.at.format("%a %d %b %Y %H:%M"),
+        resolved.at.timestamp()
+    );
+    for row in rows {
+        let names: Vec<&str> = named
+            .iter()
+            .filter(|(zone, _)| *zone == row.zone())
+            .flat_map(|(_, names)| names.iter().map(String::as_str))
+            .collect();
+        desc.push('\n');
+        desc.push_str(&row.to_string());
+        if !names.is_empty() {
+            let _ = write!(desc, " - {}", names.join(", "));
+        }
+    }
+    if let Some(warning) = resolved.warning {
+        let _ = write!(desc, "\n\n**DST:** {warning}");
+    }
+
+    ctx.send(reply!(ctx, "Time Conversion", desc, EmbedColor::Ok))
+        .await?;
+    Ok(())
+}
+
+/// collects the shared home zones of the members who can see the channel, with their names
+///
+/// only users who shared a zone are looked up. with the privileged members intent the cache
+/// holds every member of the server, otherwise the users missing from it are fetched one by one
+async fn channel_zones(ctx: Context<'_>) -> Result<Vec<(Tz, Vec<String>)>> {
+    let shared: Vec<(serenity::UserId, Tz)> = {
+        let users = ctx.data().lock().await;
+        users
+            .iter()
+            .filter_map(|(id, user)| {
+                let zone = if *id == ctx.author().id {
+                    user.home_zone()
+                } else {
+                    user.shared_zone()
+                };
+                Some((*id, zone?.parse().ok()?))
+            })
+            .collect()
+    };
+
+    let (Some(guild_id), Some(channel)) = (ctx.guild_id(), ctx.guild_channel().await) else {
+        // outside of a server only the author's zone is known
+        let author = ctx.author();
+        return Ok(shared
+            .into_iter()
+            .filter(|(id, _)| *id == author.id)
+            .map(|(_, zone)| (zone, vec![author.name.clone()]))
+            .collect());
+    };
+
+    let mut members = Vec::new();
+    for (id, zone) in shared {
+        let cached = ctx
+            .guild()
+            .and_then(|guild| guild.members.get(&id).cloned());
+        let member = match cached {
+            Some(member) => member,
+            // a complete cache means the user is not a member of the server
+            None if ctx.data().members_intent() => continue,
+            None => match guild_id.member(ctx, id).await {
+                Ok(member) => member,
+                Err(_) => continue,
+            },
+        };
+        members.push((member, zone));
+    }
+
+    let mut zones: Vec<(Tz, Vec<String>)> = Vec::new();
+    let Some(guild) = ctx.guild() else {
+        return Ok(zones);
+    };
+    for (member, zone) in members {
+        if !guild.user_permissions_in(&channel, &member).view_channel() {
+            continue;
+        }
+        let name = member.display_name().to_string();
+        match zones.iter_mut().find(|(other, _)| *other == zone) {
+            Some((_, names)) => names.push(name),
+            None => zones.push((zone, vec![name])),
+        }
+    }
+    Ok(zones)
+}
+
+/// set the timezone /tz uses when none is given
+#[poise::command(slash_command, rename = "set-home")]
+pub async fn tz_set_home(
+    ctx: Context<'_>,
+    #[description = "Your timezone, e.g. \"Europe/Berlin\", \"Berlin\" or \"CEST\". Leave empty to keep it"]
+    #[autocomplete = "autocomplete_timezone"]
+    timezone: Option<String>,
+    #[description = "Let others look up your local time with /tz for"] share: Option<bool>,
+    #[description = "Forget your home timezone"] clear: Option<bool>,
+) -> Result<()> {
+    let zone = match timezone
+        .as_deref()
+        .map(|zone| tz::zone::resolve(zone, Utc::now()))
+    {
+        Some(Ok(zone)) => Some(zone),
+        Some(Err(e)) => {
+            ctx.send(reply_error!(ctx, "could not parse input", e.to_string()))
+                .await?;
+            return Ok(());
+        }
+        None => None,
+    };
+    let clear = clear.unwrap_or(false);
+
+    let desc = {
+        let mut users = ctx.data().lock().await;
+        let user = users.get_or_create(ctx.author().id);
+        if clear {
+            user.home_zone_mut::<&str>(None);
+        } else if let Some(zone) = zone {
+            user.home_zone_mut(Some(zone.name()));
+        }
+        if let Some(share) = share {
+            user.share_zone_mut(share);
+        }
+
+        match (user.home_zone(), user.shared_zone()) {
+            (Some(zone), Some(_)) => format!("your home is {zone}, others can see your local time"),
+            (Some(zone), None) => format!("your home is {zone}, only you can see it"),
+            (None, _) if clear => "your home timezone is forgotten".to_string(),
+            (None, _) => "you have no home timezone, set one with `timezone`".to_string(),
+        }
+    };
+    let reply = reply!(ctx, "Home Timezone", desc, EmbedColor::Ok).ephemeral(true);
+    ctx.send(reply).await?;
+    Ok(())
+}
+
+/// show the local time of a member who shared their home timezone
+#[poise::command(slash_command, rename = "for")]
+pub async fn tz_for(
+    ctx: Context<'_>,
+    #[description = "Member to show the local time of"] member: serenity::User,
+) -> Result<()> {
+    local_time(ctx, &member).await
+}
+
+/// replies with the current local time and UTC offset of a user
+///
+/// the home timezone of other users is only shown if they shared it
+pub async fn local_time(ctx: Context<'_>, member: &serenity::User) -> Result<()> {
+    let zone = {
+        let users = ctx.data().lock().await;
+        users.get(&member.id).and_then(|user| {
+            if member.id == ctx.author().id {
+                user.home_zone().map(String::from)
+            } else {
+                user.shared_zone().map(String::from)
+            }
+        })
+    };
+    let Some(zone) = zone.and_then(|zone| zone.parse::<chrono_tz::Tz>().ok()) else {
+        let desc = format!("{} has not shared a home timezone", member.name);
+        ctx.send(reply_error!(ctx, "No home timezone", desc))
+            .await?;
+        return Ok(());
+    };
+
+    let now = Utc::now().with_timezone(&zone);
+    let desc = format!(
+        "it is **{}** for {} (UTC{}, {zone})",
+        now.format("%a %d %b %H:%M"),
+        member.name,
+        now.format("%:z")
+    );
+    ctx.send(reply!(ctx, "Local Time", desc, EmbedColor::Ok))
+        .await?;
     Ok(())
 }
 
@@ -108,603 +472,6 @@ async fn autocomplete_timezone<'a>(
     _ctx: Context<'_>,
     partial: &'a str,
 ) -> impl Stream<Item = String> + 'a {
-    let partial = partial.to_lowercase();
-    stream::iter(&TIMEZONES)
-        .filter(move |tz| future::ready(tz.to_lowercase().contains(&partial)))
-        .map(ToString::to_string)
+    let zones = tz::zone::suggest(partial, Utc::now());
+    stream::iter(zones).map(|zone| zone.name().to_string())
 }
-
-const TIMEZONES: [&str; 592] = [
-    "Africa/Abidjan",
-    "Africa/Accra",
-    "Africa/Addis_Ababa",
-    "Africa/Algiers",
-    "Africa/Asmara",
-    "Africa/Asmera",
-    "Africa/Bamako",
-    "Africa/Bangui",
-    "Africa/Banjul",
-    "Africa/Bissau",
-    "Africa/Blantyre",
-    "Africa/Brazzaville",
-    "Africa/Bujumbura",
-    "Africa/Cairo",
-    "Africa/Casablanca",
-    "Africa/Ceuta",
-    "Africa/Conakry",
-    "Africa/Dakar",
-    "Africa/Dar_es_Salaam",
-    "Africa/Djibouti",
-    "Africa/Douala",
-    "Africa/El_Aaiun",
-    "Africa/Freetown",
-    "Africa/Gaborone",
-    "Africa/Harare",
-    "Africa/Johannesburg",
-    "Africa/Juba",
-    "Africa/Kampala",
-    "Africa/Khartoum",
-    "Africa/Kigali",
-    "Africa/Kinshasa",
-    "Africa/Lagos",
-    "Africa/Libreville",
-    "Africa/Lome",
-    "Africa/Luanda",
-    "Africa/Lubumbashi",
-    "Africa/Lusaka",
-    "Africa/Malabo",
-    "Africa/Maputo",
-    "Africa/Maseru",
-    "Africa/Mbabane",
-    "Africa/Mogadishu",
-    "Africa/Monrovia",
-    "Africa/Nairobi",
-    "Africa/Ndjamena",
-    "Africa/Niamey",
-    "Africa/Nouakchott",
-    "Africa/Ouagadougou",
-    "Africa/Porto-Novo",
-    "Africa/Sao_Tome",
-    "Africa/Timbuktu",
-    "Africa/Tripoli",
-    "Africa/Tunis",
-    "Africa/Windhoek",
-    "America/Adak",
-    "America/Anchorage",
-    "America/Anguilla",
-    "America/Antigua",
-    "America/Araguaina",
-    "America/Argentina/Buenos_Aires",
-    "America/Argentina/Catamarca",
-    "America/Argentina/ComodRivadavia",
-    "America/Argentina/Cordoba",
-    "America/Argentina/Jujuy",
-    "America/Argentina/La_Rioja",
-    "America/Argentina/Mendoza",
-    "America/Argentina/Rio_Gallegos",
-    "America/Argentina/Salta",
-    "America/Argentina/San_Juan",
-    "America/Argentina/San_Luis",
-    "America/Argentina/Tucuman",
-    "America/Argentina/Ushuaia",
-    "America/Aruba",
-    "America/Asuncion",
-    "America/Atikokan",
-    "America/Atka",
-    "America/Bahia",
-    "America/Bahia_Banderas",
-    "America/Barbados",
-    "America/Belem",
-    "America/Belize",
-    "America/Blanc-Sablon",
-    "America/Boa_Vista",
-    "America/Bogota",
-    "America/Boise",
-    "America/Buenos_Aires",
-    "America/Cambridge_Bay",
-    "America/Campo_Grande",
-    "America/Cancun",
-    "America/Caracas",
-    "America/Catamarca",
-    "America/Cayenne",
-    "America/Cayman",
-    "America/Chicago",
-    "America/Chihuahua",
-    "America/Coral_Harbour",
-    "America/Cordoba",
-    "America/Costa_Rica",
-    "America/Creston",
-    "America/Cuiaba",
-    "America/Curacao",
-    "America/Danmarkshavn",
-    "America/Dawson",
-    "America/Dawson_Creek",
-    "America/Denver",
-    "America/Detroit",
-    "America/Dominica",
-    "America/Edmonton",
-    "America/Eirunepe",
-    "America/El_Salvador",
-    "America/Ensenada",
-    "America/Fort_Nelson",
-    "America/Fort_Wayne",
-    "America/Fortaleza",
-    "America/Glace_Bay",
-    "America/Godthab",
-    "America/Goose_Bay",
-    "America/Grand_Turk",
-    "America/Grenada",
-    "America/Guadeloupe",
-    "America/Guatemala",
-    "America/Guayaquil",
-    "America/Guyana",
-    "America/Halifax",
-    "America/Havana",
-    "America/Hermosillo",
-    "America/Indiana/Indianapolis",
-    "America/Indiana/Knox",
-    "America/Indiana/Marengo",
-    "America/Indiana/Petersburg",
-    "America/Indiana/Tell_City",
-    "America/Indiana/Vevay",
-    "America/Indiana/Vincennes",
-    "America/Indiana/Winamac",
-    "America/Indianapolis",
-    "America/Inuvik",
-    "America/Iqaluit",
-    "America/Jamaica",
-    "America/Jujuy",
-    "America/Juneau",
-    "America/Kentucky/Louisville",
-    "America/Kentucky/Monticello",
-    "America/Knox_IN",
-    "America/Kralendijk",
-    "America/La_Paz",
-    "America/Lima",
-    "America/Los_Angeles",
-    "America/Louisville",
-    "America/Lower_Princes",
-    "America/Maceio",
-    "America/Managua",
-    "America/Manaus",
-    "America/Marigot",
-    "America/Martinique",
-    "America/Matamoros",
-    "America/Mazatlan",
-    "America/Mendoza",
-    "America/Menominee",
-    "America/Merida",
-    "America/Metlakatla",
-    "America/Mexico_City",
-    "America/Miquelon",
-    "America/Moncton",
-    "America/Monterrey",
-    "America/Montevideo",
-    "America/Montreal",
-    "America/Montserrat",
-    "America/Nassau",
-    "America/New_York",
-    "America/Nipigon",
-    "America/Nome",
-    "America/Noronha",
-    "America/North_Dakota/Beulah",
-    "America/North_Dakota/Center",
-    "America/North_Dakota/New_Salem",
-    "America/Ojinaga",
-    "America/Panama",
-    "America/Pangnirtung",
-    "America/Paramaribo",
-    "America/Phoenix",
-    "America/Port-au-Prince",
-    "America/Port_of_Spain",
-    "America/Porto_Acre",
-    "America/Porto_Velho",
-    "America/Puerto_Rico",
-    "America/Punta_Arenas",
-    "America/Rainy_River",
-    "America/Rankin_Inlet",
-    "America/Recife",
-    "America/Regina",
-    "America/Resolute",
-    "America/Rio_Branco",
-    "America/Rosario",
-    "America/Santa_Isabel",
-    "America/Santarem",
-    "America/Santiago",
-    "America/Santo_Domingo",
-    "America/Sao_Paulo",
-    "America/Scoresbysund",
-    "America/Shiprock",
-    "America/Sitka",
-    "America/St_Barthelemy",
-    "America/St_Johns",
-    "America/St_Kitts",
-    "America/St_Lucia",
-    "America/St_Thomas",
-    "America/St_Vincent",
-    "America/Swift_Current",
-    "America/Tegucigalpa",
-    "America/Thule",
-    "America/Thunder_Bay",
-    "America/Tijuana",
-    "America/Toronto",
-    "America/Tortola",
-    "America/Vancouver",
-    "America/Virgin",
-    "America/Whitehorse",
-    "America/Winnipeg",
-    "America/Yakutat",
-    "America/Yellowknife",
-    "Antarctica/Casey",
-    "Antarctica/Davis",
-    "Antarctica/DumontDUrville",
-    "Antarctica/Macquarie",
-    "Antarctica/Mawson",
-    "Antarctica/McMurdo",
-    "Antarctica/Palmer",
-    "Antarctica/Rothera",
-    "Antarctica/South_Pole",
-    "Antarctica/Syowa",
-    "Antarctica/Troll",
-    "Antarctica/Vostok",
-    "Arctic/Longyearbyen",
-    "Asia/Aden",
-    "Asia/Almaty",
-    "Asia/Amman",
-    "Asia/Anadyr",
-    "Asia/Aqtau",
-    "Asia/Aqtobe",
-    "Asia/Ashgabat",
-    "Asia/Ashkhabad",
-    "Asia/Atyrau",
-    "Asia/Baghdad",
-    "Asia/Bahrain",
-    "Asia/Baku",
-    "Asia/Bangkok",
-    "Asia/Barnaul",
-    "Asia/Beirut",
-    "Asia/Bishkek",
-    "Asia/Brunei",
-    "Asia/Calcutta",
-    "Asia/Chita",
-    "Asia/Choibalsan",
-    "Asia/Chongqing",
-    "Asia/Chungking",
-    "Asia/Colombo",
-    "Asia/Dacca",
-    "Asia/Damascus",
-    "Asia/Dhaka",
-    "Asia/Dili",
-    "Asia/Dubai",
-    "Asia/Dushanbe",
-    "Asia/Famagusta",
-    "Asia/Gaza",
-    "Asia/Harbin",
-    "Asia/Hebron",
-    "Asia/Ho_Chi_Minh",
-    "Asia/Hong_Kong",
-    "Asia/Hovd",
-    "Asia/Irkutsk",
-    "Asia/Istanbul",
-    "Asia/Jakarta",
-    "Asia/Jayapura",
-    "Asia/Jerusalem",
-    "Asia/Kabul",
-    "Asia/Kamchatka",
-    "Asia/Karachi",
-    "Asia/Kashgar",
-    "Asia/Kathmandu",
-    "Asia/Katmandu",
-    "Asia/Khandyga",
-    "Asia/Kolkata",
-    "Asia/Krasnoyarsk",
-    "Asia/Kuala_Lumpur",
-    "Asia/Kuching",
-    "Asia/Kuwait",
-    "Asia/Macao",
-    "Asia/Macau",
-    "Asia/Magadan",
-    "Asia/Makassar",
-    "Asia/Manila",
-    "Asia/Muscat",
-    "Asia/Nicosia",
-    "Asia/Novokuznetsk",
-    "Asia/Novosibirsk",
-    "Asia/Omsk",
-    "Asia/Oral",
-    "Asia/Phnom_Penh",
-    "Asia/Pontianak",
-    "Asia/Pyongyang",
-    "Asia/Qatar",
-    "Asia/Qostanay",
-    "Asia/Qyzylorda",
-    "Asia/Rangoon",
-    "Asia/Riyadh",
-    "Asia/Saigon",
-    "Asia/Sakhalin",
-    "Asia/Samarkand",
-    "Asia/Seoul",
-    "Asia/Shanghai",
-    "Asia/Singapore",
-    "Asia/Srednekolymsk",
-    "Asia/Taipei",
-    "Asia/Tashkent",
-    "Asia/Tbilisi",
-    "Asia/Tehran",
-    "Asia/Tel_Aviv",
-    "Asia/Thimbu",
-    "Asia/Thimphu",
-    "Asia/Tokyo",
-    "Asia/Tomsk",
-    "Asia/Ujung_Pandang",
-    "Asia/Ulaanbaatar",
-    "Asia/Ulan_Bator",
-    "Asia/Urumqi",
-    "Asia/Ust-Nera",
-    "Asia/Vientiane",
-    "Asia/Vladivostok",
-    "Asia/Yakutsk",
-    "Asia/Yangon",
-    "Asia/Yekaterinburg",
-    "Asia/Yerevan",
-    "Atlantic/Azores",
-    "Atlantic/Bermuda",
-    "Atlantic/Canary",
-    "Atlantic/Cape_Verde",
-    "Atlantic/Faeroe",
-    "Atlantic/Faroe",
-    "Atlantic/Jan_Mayen",
-    "Atlantic/Madeira",
-    "Atlantic/Reykjavik",
-    "Atlantic/South_Georgia",
-    "Atlantic/St_Helena",
-    "Atlantic/Stanley",
-    "Australia/ACT",
-    "Australia/Adelaide",
-    "Australia/Brisbane",
-    "Australia/Broken_Hill",
-    "Australia/Canberra",
-    "Australia/Currie",
-    "Australia/Darwin",
-    "Australia/Eucla",
-    "Australia/Hobart",
-    "Australia/LHI",
-    "Australia/Lindeman",
-    "Australia/Lord_Howe",
-    "Australia/Melbourne",
-    "Australia/NSW",
-    "Australia/North",
-    "Australia/Perth",
-    "Australia/Queensland",
-    "Australia/South",
-    "Australia/Sydney",
-    "Australia/Tasmania",
-    "Australia/Victoria",
-    "Australia/West",
-    "Australia/Yancowinna",
-    "Brazil/Acre",
-    "Brazil/DeNoronha",
-    "Brazil/East",
-    "Brazil/West",
-    "CET",
-    "CST6CDT",
-    "Canada/Atlantic",
-    "Canada/Central",
-    "Canada/Eastern",
-    "Canada/Mountain",
-    "Canada/Newfoundland",
-    "Canada/Pacific",
-    "Canada/Saskatchewan",
-    "Canada/Yukon",
-    "Chile/Continental",
-    "Chile/EasterIsland",
-    "Cuba",
-    "EET",
-    "EST",
-    "EST5EDT",
-    "Egypt",
-    "Eire",
-    "Etc/GMT",
-    "Etc/GMT+0",
-    "Etc/GMT+1",
-    "Etc/GMT+10",
-    "Etc/GMT+11",
-    "Etc/GMT+12",
-    "Etc/GMT+2",
-    "Etc/GMT+3",
-    "Etc/GMT+4",
-    "Etc/GMT+5",
-    "Etc/GMT+6",
-    "Etc/GMT+7",
-    "Etc/GMT+8",
-    "Etc/GMT+9",
-    "Etc/GMT-0",
-    "Etc/GMT-1",
-    "Etc/GMT-10",
-    "Etc/GMT-11",
-    "Etc/GMT-12",
-    "Etc/GMT-13",
-    "Etc/GMT-14",
-    "Etc/GMT-2",
-    "Etc/GMT-3",
-    "Etc/GMT-4",
-    "Etc/GMT-5",
-    "Etc/GMT-6",
-    "Etc/GMT-7",
-    "Etc/GMT-8",
-    "Etc/GMT-9",
-    "Etc/GMT0",
-    "Etc/Greenwich",
-    "Etc/UCT",
-    "Etc/UTC",
-    "Etc/Universal",
-    "Etc/Zulu",
-    "Europe/Amsterdam",
-    "Europe/Andorra",
-    "Europe/Astrakhan",
-    "Europe/Athens",
-    "Europe/Belfast",
-    "Europe/Belgrade",
-    "Europe/Berlin",
-    "Europe/Bratislava",
-    "Europe/Brussels",
-    "Europe/Bucharest",
-    "Europe/Budapest",
-    "Europe/Busingen",
-    "Europe/Chisinau",
-    "Europe/Copenhagen",
-    "Europe/Dublin",
-    "Europe/Gibraltar",
-    "Europe/Guernsey",
-    "Europe/Helsinki",
-    "Europe/Isle_of_Man",
-    "Europe/Istanbul",
-    "Europe/Jersey",
-    "Europe/Kaliningrad",
-    "Europe/Kiev",
-    "Europe/Kirov",
-    "Europe/Lisbon",
-    "Europe/Ljubljana",
-    "Europe/London",
-    "Europe/Luxembourg",
-    "Europe/Madrid",
-    "Europe/Malta",
-    "Europe/Mariehamn",
-    "Europe/Minsk",
-    "Europe/Monaco",
-    "Europe/Moscow",
-    "Europe/Nicosia",
-    "Europe/Oslo",
-    "Europe/Paris",
-    "Europe/Podgorica",
-    "Europe/Prague",
-    "Europe/Riga",
-    "Europe/Rome",
-    "Europe/Samara",
-    "Europe/San_Marino",
-    "Europe/Sarajevo",
-    "Europe/Saratov",
-    "Europe/Simferopol",
-    "Europe/Skopje",
-    "Europe/Sofia",
-    "Europe/Stockholm",
-    "Europe/Tallinn",
-    "Europe/Tirane",
-    "Europe/Tiraspol",
-    "Europe/Ulyanovsk",
-    "Europe/Uzhgorod",
-    "Europe/Vaduz",
-    "Europe/Vatican",
-    "Europe/Vienna",
-    "Europe/Vilnius",
-    "Europe/Volgograd",
-    "Europe/Warsaw",
-    "Europe/Zagreb",
-    "Europe/Zaporozhye",
-    "Europe/Zurich",
-    "GB",
-    "GB-Eire",
-    "GMT",
-    "GMT+0",
-    "GMT-0",
-    "GMT0",
-    "Greenwich",
-    "HST",
-    "Hongkong",
-    "Iceland",
-    "Indian/Antananarivo",
-    "Indian/Chagos",
-    "Indian/Christmas",
-    "Indian/Cocos",
-    "Indian/Comoro",
-    "Indian/Kerguelen",
-    "Indian/Mahe",
-    "Indian/Maldives",
-    "Indian/Mauritius",
-    "Indian/Mayotte",
-    "Indian/Reunion",
-    "Iran",
-    "Israel",
-    "Jamaica",
-    "Japan",
-    "Kwajalein",
-    "Libya",
-    "MET",
-    "MST",
-    "MST7MDT",
-    "Mexico/BajaNorte",
-    "Mexico/BajaSur",
-    "Mexico/General",
-    "NZ",
-    "NZ-CHAT",
-    "Navajo",
-    "PRC",
-    "PST8PDT",
-    "Pacific/Apia",
-    "Pacific/Auckland",
-    "Pacific/Bougainville",
-    "Pacific/Chatham",
-    "Pacific/Chuuk",
-    "Pacific/Easter",
-    "Pacific/Efate",
-    "Pacific/Enderbury",
-    "Pacific/Fakaofo",
-    "Pacific/Fiji",
-    "Pacific/Funafuti",
-    "Pacific/Galapagos",
-    "Pacific/Gambier",
-    "Pacific/Guadalcanal",
-    "Pacific/Guam",
-    "Pacific/Honolulu",
-    "Pacific/Johnston",
-    "Pacific/Kiritimati",
-    "Pacific/Kosrae",
-    "Pacific/Kwajalein",
-    "Pacific/Majuro",
-    "Pacific/Marquesas",
-    "Pacific/Midway",
-    "Pacific/Nauru",
-    "Pacific/Niue",
-    "Pacific/Norfolk",
-    "Pacific/Noumea",
-    "Pacific/Pago_Pago",
-    "Pacific/Palau",
-    "Pacific/Pitcairn",
-    "Pacific/Pohnpei",
-    "Pacific/Ponape",
-    "Pacific/Port_Moresby",
-    "Pacific/Rarotonga",
-    "Pacific/Saipan",
-    "Pacific/Samoa",
-    "Pacific/Tahiti",
-    "Pacific/Tarawa",
-    "Pacific/Tongatapu",
-    "Pacific/Truk",
-    "Pacific/Wake",
-    "Pacific/Wallis",
-    "Pacific/Yap",
-    "Poland",
-    "Portugal",
-    "ROC",
-    "ROK",
-    "Singapore",
-    "Turkey",
-    "UCT",
-    "US/Alaska",
-    "US/Aleutian",
-    "US/Arizona",
-    "US/Central",
-    "US/East-Indiana",
-    "US/Eastern",
-    "US/Hawaii",
-    "US/Indiana-Starke",
-    "US/Michigan",
-    "US/Mountain",
-    "US/Pacific",
-    "US/Samoa",
-    "UTC",
-    "Universal",
-    "W-SU",
-    "WET",
-    "Zulu",
-];
@@ -0,0 +1,81 @@
+use crate::{
+    utils::{
+        self,
+        macros::{discord::reply, EmbedColor},
+    },
+    Context, Result,
+};
+
+use walzecore::dice::{system, DiceRoll};
+
+/// reproduce a previous roll from its roll id once its seed is revealed
+#[poise::command(slash_command)]
+pub async fn verify(
+    ctx: Context<'_>,
+    #[description = "Roll id shown in the footer of an eval result"] roll_id: String,
+) -> Result<()> {
+    let ledger = ctx.data().ledger().lock().await;
+    let (record, rng) = ledger.verify(&roll_id)?;
+    let expr = record.expr.clone();
+    let expected = record.results.clone();
+    let system = match &record.system {
+        Some(name) => system::by_name(name)?,
+        None => system::default_system(),
+    };
+    drop(ledger);
+
+    let rolls = utils::roll_dice_guarded(&expr, system, rng, ctx.data().limits()).await?;
+    let matches = rolls.iter().map(DiceRoll::description).eq(expected);
+
+    let mut desc = format!("```\n{expr}\n```\n");
+    for roll in &rolls {
+        desc.push_str(&format!("{}: {}\n", roll.label(), roll.description()));
+    }
+
+    let (title, color) = if matches {
+        (format!("Roll {roll_id} verified"), EmbedColor::Ok)
+    } else {
+        (format!("Roll {roll_id} does not match"), EmbedColor::Error)
+    };
+    ctx.send(reply!(ctx, title, desc, color)).await?;
+    Ok(())
+}
+
+#[allow(clippy::unused_async)]
+#[poise::command(slash_command, subcommands("seed_commitment", "seed_reveal"))]
+pub async fn seed(_: Context<'_>) -> Result<()> {
+    Ok(())
+}
+
+/// show the hash of the current secret seed
+#[poise::command(slash_command, rename = "commitment")]
+pub async fn seed_commitment(ctx: Context<'_>) -> Result<()> {
+    let ledger = ctx.data().ledger().lock().await;
+    let desc = format!(
+        "Rolls in epoch {} are derived from a seed with the SHA-256 hash\n```\n{}\n```",
+        ledger.epoch(),
+        ledger.commitment()
+    );
+    drop(ledger);
+
+    ctx.send(reply!(ctx, "Seed Commitment", desc, EmbedColor::Ok))
+        .await?;
+    Ok(())
+}
+
+/// reveal the current secret seed and start a new epoch
+#[poise::command(slash_command, rename = "reveal", owners_only)]
+pub async fn seed_reveal(ctx: Context<'_>) -> Result<()> {
+    let mut ledger = ctx.data().ledger().lock().await;
+    let (epoch, secret) = ledger.reveal();
+    let desc = format!(
+        "Seed of epoch {epoch}\n```\n{secret}\n```\nRolls of this epoch can now be checked with `/verify`.\nNew commitment\n```\n{}\n```",
+        ledger.commitment()
+    );
+    drop(ledger);
+    ctx.data().save_ledger().await?;
+
+    ctx.send(reply!(ctx, "Seed Revealed", desc, EmbedColor::Ok))
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use crate::{commands::eval, Context, Result};
+
+use walzecore::dice::system::Wrath;
+
+/// roll a Wrath & Glory dice pool with its wrath die
+#[poise::command(slash_command)]
+pub async fn wrath(
+    ctx: Context<'_>,
+    #[description = "Number of dice in the pool, wrath dice included"]
+    #[min = 1]
+    pool: u64,
+    #[description = "Difficulty number to meet with icons"] dn: Option<u64>,
+    #[description = "Number of wrath dice in the pool, 1 by default"] wrath: Option<u64>,
+    #[description = "What the roll is for"] label: Option<String>,
+) -> Result<()> {
+    let mut expr = match wrath {
+        Some(wrath) => format!("wrath({pool}, {wrath})"),
+        None => format!("wrath({pool})"),
+    };
+    if let Some(dn) = dn {
+        expr.push_str(&format!(" vs {dn}"));
+    }
+
+    eval::send_labeled_roll(ctx, &expr, label, Some(&Wrath)).await
+}
@@ -10,15 +10,26 @@ mod error;
 mod models;
 mod utils;
 
+use commands::bands;
 use commands::context_cmd;
 use commands::eval;
+use commands::gmroll;
+use commands::inline;
+use commands::percentile;
+use commands::schedule;
 use commands::tz;
+use commands::verify;
+use commands::wrath;
 use dotenvy::dotenv;
 use poise::serenity_prelude as serenity;
-use serenity::UserId;
+use serenity::{GuildId, UserId};
 use tokio::{fs::OpenOptions, io::AsyncReadExt};
-use tracing::{debug, error, info};
-use walzecore::db::Users;
+use tracing::{debug, error, info, warn};
+use walzecore::{
+    db::{Guilds, Users},
+    dice::{limits::Limits, Ledger},
+    schedule::Schedules,
+};
 
 use crate::{
     commands::alias,
@@ -44,18 +55,44 @@ async fn run() -> Result<()> {
     dotenv().ok();
 
     let users = load_users_from_file().await?;
-    let data = Data::new(users);
+    let guilds = load_guilds_from_file().await?;
+    let ledger = load_ledger_from_file().await?;
+    let schedules = load_schedules_from_file().await?;
+    // finding every member with the GM role or sharing a timezone needs the privileged members
+    // intent, without it only the members the bot has seen, e.g. in voice, are cached
+    let members = std::env::var("WALZE_GUILD_MEMBERS").is_ok_and(|v| v == "1" || v == "true");
+    let data = Data::new(users, guilds, ledger, schedules, limits_from_env(), members);
 
     let token = std::env::var("DISCORD_API")?;
-    let intents = serenity::GatewayIntents::non_privileged();
+    let mut intents = serenity::GatewayIntents::non_privileged();
+    // answering [[...]] rolls needs the privileged message content intent,
+    // which has to be enabled for the bot before it can be requested
+    if std::env::var("WALZE_INLINE_ROLLS").is_ok_and(|v| v == "1" || v == "true") {
+        intents |= serenity::GatewayIntents::MESSAGE_CONTENT;
+    }
+    if members {
+        intents |= serenity::GatewayIntents::GUILD_MEMBERS;
+    }
 
     let commands = vec![
         eval::eval(),
+        wrath::wrath(),
+        bands::blades(),
+        bands::pbta(),
+        percentile::coc(),
+        gmroll::gmroll(),
+        gmroll::gmrole(),
+        inline::inlinerolls(),
+        verify::verify(),
+        verify::seed(),
         alias::alias(),
         alias::namespace(),
         context_cmd::help(),
         context_cmd::echo(),
-        tz::tzcalc(),
+        context_cmd::roll_here(),
+        context_cmd::local_time(),
+        tz::tz(),
+        schedule::schedule(),
     ];
 
     let options = poise::FrameworkOptions {
@@ -71,12 +108,31 @@ async fn run() -> Result<()> {
                 info!("Executed command {}", ctx.command().qualified_name);
             })
         },
-        event_handler: |_ctx, event, _framework, _data| {
+        event_handler: |ctx, event, _framework, data| {
             Box::pin(async move {
                 debug!(
                     "Got an event in event handler: {:?}",
                     event.snake_case_name(),
                 );
+                match event {
+                    serenity::FullEvent::Message { new_message } => {
+                        if let Err(e) = inline::handle_message(ctx, data, new_message).await {
+                            warn!("failed to answer inline rolls: {e}");
+                        }
+                    }
+                    // a guild only comes with its members if it is small, the rest are
+                    // requested in chunks that fill the cache as they arrive
+                    serenity::FullEvent::GuildCreate { guild, .. } if data.members_intent() => {
+                        ctx.shard.chunk_guild(
+                            guild.id,
+                            None,
+                            false,
+                            serenity::ChunkGuildFilter::None,
+                            None,
+                        );
+                    }
+                    _ => {}
+                }
                 Ok(())
             })
         },
@@ -89,6 +145,7 @@ async fn run() -> Result<()> {
             Box::pin(async move {
                 poise::builtins::register_globally(ctx, &framework.options().commands).await?;
                 debug!("commands registered globally");
+                tokio::spawn(schedule::remind(ctx.http.clone(), data.schedules().clone()));
                 Ok(data)
             })
         })
@@ -129,12 +186,42 @@ async fn on_error(err: poise::FrameworkError<'_, Data, crate::error::Error>) {
 
 // Load the users data from JSON file
 async fn load_users_from_file() -> Result<Users<UserId>> {
+    let json = read_json_file("users.json").await?;
+    let users = Users::new(&json)?;
+    Ok(users)
+}
+
+// Load the guild settings from JSON file
+async fn load_guilds_from_file() -> Result<Guilds<GuildId>> {
+    let json = read_json_file("guilds.json").await?;
+    let guilds = Guilds::new(&json)?;
+    Ok(guilds)
+}
+
+// Load the roll ledger from JSON file
+async fn load_ledger_from_file() -> Result<Ledger> {
+    let json = read_json_file("rolls.json").await?;
+    let ledger = Ledger::new(&json)?;
+    // a new ledger starts with a secret that has to be on disk before any roll uses it
+    models::write_file("rolls.json", &ledger.to_json()).await?;
+    Ok(ledger)
+}
+
+// Load the session schedules from JSON file
+async fn load_schedules_from_file() -> Result<Schedules> {
+    let json = read_json_file("schedules.json").await?;
+    let schedules = Schedules::new(&json)?;
+    Ok(schedules)
+}
+
+// Read a JSON file, creating it if it does not exist
+async fn read_json_file(path: &str) -> Result<String> {
     let mut file = OpenOptions::new()
         .create(true)
         .truncate(false)
         .read(true)
         .write(true)
-        .open("users.json")
+        .open(path)
         .await?;
 
     let mut json = String::new();
@@ -144,6 +231,24 @@ async fn load_users_from_file() -> Result<Users<UserId>> {
         json.push_str("{}");
     }
 
-    let users = Users::new(&json)?;
-    Ok(users)
+    Ok(json)
+}
+
+// Read the dice expression limits from the environment, keeping the defaults for unset values
+fn limits_from_env() -> Limits {
+    fn var<T: std::str::FromStr>(key: &str, default: T) -> T {
+        std::env::var(key)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(default)
+    }
+
+    let default = Limits::default();
+    Limits {
+        max_dice: var("WALZE_MAX_DICE", default.max_dice),
+        max_sides: var("WALZE_MAX_SIDES", default.max_sides),
+        max_explosions: var("WALZE_MAX_EXPLOSIONS", default.max_explosions),
+        max_parts: var("WALZE_MAX_PARTS", default.max_parts),
+        max_length: var("WALZE_MAX_LENGTH", default.max_length),
+    }
 }
@@ -2,18 +2,89 @@ use std::{ops::Deref, sync::Arc};
 
 use poise::serenity_prelude as serenity;
 use tokio::sync::Mutex;
-use walzecore::db::Users;
+use walzecore::{
+    db::{Guilds, Users},
+    dice::{limits::Limits, Ledger},
+    schedule::Schedules,
+};
 
 use crate::error::Error;
 
-/// `Data` struct holds the users's dice rolls, which is an `Arc<Mutex<Users<serenity::UserId>>>`.
+/// `Data` struct holds the users's dice rolls, which is an `Arc<Mutex<Users<serenity::UserId>>>`,
+/// the guild settings, the roll ledger used to verify rolls, the session schedules, the limits
+/// every expression is checked against and whether the bot sees every member of a guild.
 #[derive(Debug)]
-pub struct Data(Arc<Mutex<Users<serenity::UserId>>>);
+pub struct Data {
+    users: Arc<Mutex<Users<serenity::UserId>>>,
+    guilds: Arc<Mutex<Guilds<serenity::GuildId>>>,
+    ledger: Arc<Mutex<Ledger>>,
+    schedules: Arc<Mutex<Schedules>>,
+    limits: Limits,
+    members: bool,
+}
 
 impl Data {
-    /// Creates a new `Data` instance by wrapping the `Users` data in an `Arc` and `Mutex`.
-    pub fn new(users: Users<serenity::UserId>) -> Self {
-        Self(Arc::new(Mutex::new(users)))
+    /// Creates a new `Data` instance by wrapping the `Users`, `Guilds`, `Ledger` and `Schedules`
+    /// data in an `Arc` and `Mutex`.
+    pub fn new(
+        users: Users<serenity::UserId>,
+        guilds: Guilds<serenity::GuildId>,
+        ledger: Ledger,
+        schedules: Schedules,
+        limits: Limits,
+        members: bool,
+    ) -> Self {
+        Self {
+            users: Arc::new(Mutex::new(users)),
+            guilds: Arc::new(Mutex::new(guilds)),
+            ledger: Arc::new(Mutex::new(ledger)),
+            schedules: Arc::new(Mutex::new(schedules)),
+            limits,
+            members,
+        }
+    }
+
+    /// Returns the guild settings.
+    pub fn guilds(&self) -> &Mutex<Guilds<serenity::GuildId>> {
+        &self.guilds
+    }
+
+    /// Returns the seeded roll ledger.
+    pub fn ledger(&self) -> &Mutex<Ledger> {
+        &self.ledger
+    }
+
+    /// Writes the roll ledger to `rolls.json`.
+    ///
+    /// Called whenever a secret seed is created or revealed, so a crash cannot lose the secret
+    /// behind a published commitment.
+    pub async fn save_ledger(&self) -> Result<(), Error> {
+        let json = self.ledger.lock().await.to_json();
+        write_file("rolls.json", &json).await
+    }
+
+    /// Returns the session schedules, shared with the task sending their reminders.
+    pub fn schedules(&self) -> &Arc<Mutex<Schedules>> {
+        &self.schedules
+    }
+
+    /// Writes the session schedules to `schedules.json`.
+    ///
+    /// Called whenever a schedule changes, so a crash cannot lose it.
+    pub async fn save_schedules(&self) -> Result<(), Error> {
+        let json = self.schedules.lock().await.to_json();
+        write_file("schedules.json", &json).await
+    }
+
+    /// Returns the limits dice expressions are checked against.
+    pub fn limits(&self) -> Limits {
+        self.limits
+    }
+
+    /// Returns `true` if the bot requested the privileged members intent, so the cache holds
+    /// every member of a guild instead of only those it has seen.
+    pub fn members_intent(&self) -> bool {
+        self.members
     }
 }
 
@@ -21,17 +92,27 @@ impl Deref for Data {
     type Target = Mutex<Users<serenity::UserId>>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.users
     }
 }
 
+/// Writes a file through a temporary file that replaces it, so a crash while writing leaves
+/// the previous contents intact.
+pub async fn write_file(path: &str, contents: &str) -> Result<(), Error> {
+    let temp = format!("{path}.tmp");
+    tokio::fs::write(&temp, contents).await?;
+    tokio::fs::rename(&temp, path).await?;
+    Ok(())
+}
+
 /// Type alias for `poise::Context` with the `Data` struct as the data type and `Error` as the error type.
 pub type Context<'a> = poise::Context<'a, Data, Error>;
 
 impl Drop for Data {
-    /// When the `Data` instance is dropped, we want to write whatever is written into the `users.json` file. 
+    /// When the `Data` instance is dropped, we want to write whatever is written into the `users.json`,
+    /// `guilds.json`, `rolls.json` and `schedules.json` files.
     fn drop(&mut self) {
-        if let Ok(users) = self.0.try_lock() {
+        if let Ok(users) = self.users.try_lock() {
             let string = users.to_json();
             if let Err(e) = std::fs::write("users.json", string) {
                 eprintln!("Error writing users.json file: {e}");
@@ -39,5 +120,29 @@ impl Drop for Data {
         } else {
             eprintln!("Failed to acquire lock for writing users.json file");
         }
+
+        if let Ok(guilds) = self.guilds.try_lock() {
+            if let Err(e) = std::fs::write("guilds.json", guilds.to_json()) {
+                eprintln!("Error writing guilds.json file: {e}");
+            }
+        } else {
+            eprintln!("Failed to acquire lock for writing guilds.json file");
+        }
+
+        if let Ok(ledger) = self.ledger.try_lock() {
+            if let Err(e) = std::fs::write("rolls.json", ledger.to_json()) {
+                eprintln!("Error writing rolls.json file: {e}");
+            }
+        } else {
+            eprintln!("Failed to acquire lock for writing rolls.json file");
+        }
+
+        if let Ok(schedules) = self.schedules.try_lock() {
+            if let Err(e) = std::fs::write("schedules.json", schedules.to_json()) {
+                eprintln!("Error writing schedules.json file: {e}");
+            }
+        } else {
+            eprintln!("Failed to acquire lock for writing schedules.json file");
+        }
     }
 }
@@ -0,0 +1,252 @@
+use std::fmt::Write;
+
+use poise::serenity_prelude::{CreateAttachment, CreateEmbed};
+use walzecore::dice::{system::bands::Tier, DiceRoll};
+
+use super::macros::EmbedColor;
+
+/// maximum length of an embed title
+pub const EMBED_TITLE_LIMIT: usize = 256;
+/// maximum length of an embed description
+pub const EMBED_DESCRIPTION_LIMIT: usize = 4096;
+/// maximum number of fields in a single embed
+pub const EMBED_FIELD_COUNT_LIMIT: usize = 25;
+/// maximum length of an embed field name
+pub const EMBED_FIELD_NAME_LIMIT: usize = 256;
+/// maximum length of an embed field value
+pub const EMBED_FIELD_VALUE_LIMIT: usize = 1024;
+/// maximum combined length of all text in an embed
+pub const EMBED_TOTAL_LIMIT: usize = 6000;
+
+/// name of the text file attached when the results do not fit into an embed
+pub const ATTACHMENT_NAME: &str = "rolls.txt";
+
+/// how a set of rolls is presented in a reply
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Layout {
+    /// a single embed with the result as its description
+    Single { title: String, description: String },
+    /// a single embed with one field per roll
+    Fields {
+        title: String,
+        description: String,
+        fields: Vec<(String, String)>,
+    },
+    /// a single embed with a summary and every result in an attached text file
+    File {
+        title: String,
+        description: String,
+        contents: String,
+    },
+}
+
+impl Layout {
+    /// fills the title, description and fields of the embed,
+    /// returning the attachment to send along with it if there is one
+    pub fn apply(self, embed: CreateEmbed) -> (CreateEmbed, Option<CreateAttachment>) {
+        match self {
+            Layout::Single { title, description } => {
+                (embed.title(title).description(description), None)
+            }
+            Layout::Fields {
+                title,
+                description,
+                fields,
+            } => {
+                let fields = fields.into_iter().map(|(name, value)| (name, value, false));
+                (
+                    embed.title(title).description(description).fields(fields),
+                    None,
+                )
+            }
+            Layout::File {
+                title,
+                description,
+                contents,
+            } => (
+                embed.title(title).description(description),
+                Some(CreateAttachment::bytes(
+                    contents.into_bytes(),
+                    ATTACHMENT_NAME,
+                )),
+            ),
+        }
+    }
+}
+
+/// packs the rolls into one embed, falling back to an attached text file
+/// when they do not fit into Discord's embed limits
+///
+/// `footer` is the text of the footer the embed is sent with, which counts towards the limits
+pub fn layout(expr: &str, rolls: &[DiceRoll], footer: &str) -> Layout {
+    let title = truncate(expr, EMBED_TITLE_LIMIT);
+    let grand_total = grand_total(rolls);
+
+    let descriptions: Vec<String> = rolls.iter().map(DiceRoll::description).collect();
+
+    if let ([roll], [description]) = (rolls, descriptions.as_slice()) {
+        if description.len() <= EMBED_DESCRIPTION_LIMIT {
+            return Layout::Single {
+                title: truncate(roll.label(), EMBED_TITLE_LIMIT),
+                description: description.clone(),
+            };
+        }
+    }
+
+    let description = grand_total
+        .map(|total| format!("grand total: **{total}**"))
+        .unwrap_or_default();
+
+    let fits_fields = rolls.len() <= EMBED_FIELD_COUNT_LIMIT
+        && rolls.iter().zip(&descriptions).all(|(roll, desc)| {
+            roll.label().len() <= EMBED_FIELD_NAME_LIMIT && desc.len() <= EMBED_FIELD_VALUE_LIMIT
+        })
+        && title.len()
+            + description.len()
+            + footer.len()
+            + rolls
+                .iter()
+                .zip(&descriptions)
+                .map(|(roll, desc)| roll.label().len() + desc.len())
+                .sum::<usize>()
+            <= EMBED_TOTAL_LIMIT;
+
+    if fits_fields {
+        let fields = rolls
+            .iter()
+            .zip(descriptions)
+            .map(|(roll, desc)| (roll.label().to_string(), desc))
+            .collect();
+        return Layout::Fields {
+            title,
+            description,
+            fields,
+        };
+    }
+
+    let mut contents = String::new();
+    for (roll, desc) in rolls.iter().zip(&descriptions) {
+        let _ = writeln!(contents, "{}\n{}\n", roll.label(), desc.replace("**", ""));
+    }
+    if let Some(total) = grand_total {
+        let _ = writeln!(contents, "grand total: {total}");
+    }
+
+    let mut description = format!("{} results attached as `{ATTACHMENT_NAME}`", rolls.len());
+    if let Some(total) = grand_total {
+        let _ = write!(description, "\ngrand total: **{total}**");
+    }
+
+    Layout::File {
+        title,
+        description,
+        contents,
+    }
+}
+
+/// color of the reply, reflecting critical rolls and the verdicts or outcome tiers of the rolls
+pub fn color(rolls: &[DiceRoll]) -> EmbedColor {
+    let successes: Vec<bool> = rolls
+        .iter()
+        .filter_map(|roll| match (roll.verdict, roll.tier) {
+            (Some(verdict), _) => Some(verdict.success),
+            (None, Some(Tier::Failure)) => Some(false),
+            (None, Some(Tier::Success | Tier::Critical)) => Some(true),
+            _ => None,
+        })
+        .collect();
+    if rolls.iter().any(DiceRoll::is_critical) {
+        EmbedColor::Critical
+    } else if successes.is_empty() {
+        EmbedColor::Ok
+    } else if successes.iter().all(|&success| success) {
+        EmbedColor::Success
+    } else if successes.iter().all(|&success| !success) {
+        EmbedColor::Failure
+    } else {
+        EmbedColor::Ok
+    }
+}
+
+/// sum of all totals, if there is more than one roll and every roll has a total
+fn grand_total(rolls: &[DiceRoll]) -> Option<i64> {
+    if rolls.len() < 2 {
+        return None;
+    }
+    rolls.iter().map(|roll| roll.total).sum()
+}
+
+/// cuts a string down to at most `limit` bytes on a char boundary
+fn truncate(s: &str, limit: usize) -> String {
+    if s.len() <= limit {
+        return s.to_string();
+    }
+    let mut end = limit.saturating_sub(3);
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}...", &s[..end])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roll(expr: &str, result: &str) -> DiceRoll {
+        DiceRoll::new(expr, result.to_string(), Some(1))
+    }
+
+    #[test]
+    fn single_roll_is_the_description() {
+        let rolls = [roll("1d20", "[1] = 1")];
+        let layout = layout("1d20", &rolls, "roll id: 0-0");
+        assert!(matches!(layout, Layout::Single { .. }));
+    }
+
+    #[test]
+    fn several_rolls_are_fields() {
+        let rolls = [roll("1d20", "[1] = 1"), roll("1d6", "[1] = 1")];
+        let Layout::Fields {
+            fields,
+            description,
+            ..
+        } = layout("1d20, 1d6", &rolls, "roll id: 0-0")
+        else {
+            panic!("expected fields");
+        };
+        assert_eq!(fields.len(), 2);
+        assert_eq!(description, "grand total: **2**");
+    }
+
+    #[test]
+    fn too_many_rolls_overflow_into_an_attachment() {
+        let rolls = vec![roll("1d6", "[1] = 1"); EMBED_FIELD_COUNT_LIMIT + 1];
+        let Layout::File { contents, .. } = layout("1d6", &rolls, "") else {
+            panic!("expected an attachment");
+        };
+        assert_eq!(
+            contents.matches("[1] = 1").count(),
+            EMBED_FIELD_COUNT_LIMIT + 1
+        );
+    }
+
+    #[test]
+    fn long_field_overflows_into_an_attachment() {
+        let long = "1".repeat(EMBED_FIELD_VALUE_LIMIT + 1);
+        let rolls = [roll("1d6", &long), roll("1d6", "[1] = 1")];
+        assert!(matches!(layout("1d6", &rolls, ""), Layout::File { .. }));
+    }
+
+    #[test]
+    fn footer_counts_towards_the_total_limit() {
+        // eight fields that together fill the embed up to just below its total limit
+        let expr = "1d6";
+        let per_roll = (EMBED_TOTAL_LIMIT - expr.len() - "grand total: **8**".len()) / 8;
+        let result = "1".repeat(per_roll - expr.len());
+        let rolls = vec![roll(expr, &result); 8];
+
+        assert!(matches!(layout(expr, &rolls, ""), Layout::Fields { .. }));
+        let footer = "roll id: 1f-2a";
+        assert!(matches!(layout(expr, &rolls, footer), Layout::File { .. }));
+    }
+}
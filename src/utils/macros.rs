@@ -1,7 +1,10 @@
 #[rustfmt::skip]
 pub enum EmbedColor {
-    Error   = 0x00FF_3333,
-    Ok      = 0x0072_A3C3,
+    Error    = 0x00FF_3333,
+    Ok       = 0x0072_A3C3,
+    Success  = 0x0043_B581,
+    Failure  = 0x00ED_4245,
+    Critical = 0x00FA_A61A,
 }
 
 impl From<EmbedColor> for poise::serenity_prelude::Colour {
@@ -1,5 +1,29 @@
+pub mod layout;
 pub mod macros;
 
+use std::{
+    fmt::Write,
+    time::{Duration, Instant},
+};
+
+use caith::Roller;
+use rand::Rng;
+use walzecore::dice::{
+    attack::Attack,
+    expr::{dice_terms, natural_face, split, split_label},
+    limits::Limits,
+    repeat::{Repeat, Table},
+    system::{self, blades::Action, pbta::Move, percentile::Check, wrath::Pool, Dice},
+    target::Target,
+    DiceRoll, GameSystem,
+};
+
+use crate::error::Result;
+use layout::EMBED_DESCRIPTION_LIMIT;
+
+/// how long a single evaluation may take before it is abandoned
+const ROLL_TIMEOUT: Duration = Duration::from_secs(5);
+
 // Helper functions
 
 /// removes all asterisks and backticks from the string
@@ -7,10 +31,225 @@ pub fn normalize_dice_expr(s: &str) -> String {
     s.replace(['*', '`'], "")
 }
 
-/// splits dice string with commas: ,
-pub fn split_dice(expr: &str) -> Vec<&str> {
-    expr.split(',')
-        .map(str::trim)
-        .filter(|s| !s.is_empty())
+/// rolls every comma separated part of the expression with the given rng,
+/// interpreting plain rolls the way the game system reads them
+///
+/// parts in the syntax of another game system are refused, so the binding of the namespace
+/// decides which syntax is rolled. rolling stops with an error once the deadline has passed
+pub fn roll_dice<R: Rng>(
+    expr: &str,
+    system: &dyn GameSystem,
+    rng: &mut R,
+    deadline: Instant,
+) -> Result<Vec<DiceRoll>> {
+    let mut results = Vec::new();
+    for part in split(expr) {
+        check_deadline(deadline)?;
+        let (label, roll) = split_label(part);
+        if let Some(other) = system::foreign_syntax(system, roll) {
+            return Err(format!(
+                "`{roll}` is rolled in namespaces bound to {0}, bind one with `/namespace system {0}`",
+                other.name()
+            )
+            .into());
+        }
+        let mut dice_roll = if let Some(repeat) = Repeat::parse(roll)? {
+            let (result, total) = roll_repeat(repeat, rng, deadline)?;
+            DiceRoll::new(roll, result, total)
+        } else if let Some(pool) = Pool::parse(Target::split(roll).0)? {
+            roll_pool(roll, pool, rng)
+        } else if let Some(action) = Action::parse(roll) {
+            roll_action(roll, action, rng)
+        } else if let Some(pbta_move) = Move::parse(roll) {
+            roll_move(roll, pbta_move, rng)
+        } else if let Some(check) = Check::parse(roll)? {
+            roll_percentile(roll, check, rng)
+        } else if let Some(attack) = Attack::parse(roll)? {
+            roll_attack(roll, &attack, rng)?
+        } else {
+            roll_checked(roll, system, rng)?
+        };
+        dice_roll.label = label.map(ToString::to_string);
+        results.push(dice_roll);
+    }
+    Ok(results)
+}
+
+/// rolls a single expression, checking it against its target if it has one
+/// and letting the game system interpret the dice
+fn roll_checked<R: Rng>(roll: &str, system: &dyn GameSystem, rng: &mut R) -> Result<DiceRoll> {
+    let (expr, target) = Target::split(roll);
+    let (result, total, dice) = roll_single(expr, rng)?;
+
+    let mut dice_roll = DiceRoll::new(roll, result, total);
+    if let Some(target) = target {
+        let total = total.ok_or_else(|| format!("cannot compare {expr}: it has no total"))?;
+        dice_roll.verdict = Some(target.check(total, None));
+    }
+    system.interpret(&mut dice_roll, &dice);
+    Ok(dice_roll)
+}
+
+/// rolls the to hit part of an attack and, if it hits, its damage
+fn roll_attack<R: Rng>(roll: &str, attack: &Attack<'_>, rng: &mut R) -> Result<DiceRoll> {
+    let (expr, _) = Target::split(attack.to_hit);
+    let (hit, hit_total, dice) = roll_single(expr, rng)?;
+    let hit_total =
+        hit_total.ok_or_else(|| format!("cannot attack with {expr}: it has no total"))?;
+    // the kept die when attacking with advantage or disadvantage
+    let faces = dice.first().map_or(&[][..], |dice| dice.faces.as_slice());
+    let outcome = attack.resolve(hit_total, natural_face(expr, faces));
+
+    let mut result = format!("to hit: {hit}");
+    let mut total = None;
+    if outcome.hits {
+        let (damage, damage_total, _) = roll_single(&attack.damage(&outcome), rng)?;
+        let _ = write!(result, "\ndamage: {damage}");
+        total = damage_total;
+    } else {
+        result.push_str("\nmiss");
+    }
+
+    let mut dice_roll = DiceRoll::new(roll, result, total);
+    dice_roll.verdict = outcome.verdict;
+    dice_roll.natural = outcome.natural;
+    Ok(dice_roll)
+}
+
+/// rolls a wrath & glory dice pool, checking its icons against the difficulty number if it has one
+fn roll_pool<R: Rng>(roll: &str, pool: Pool, rng: &mut R) -> DiceRoll {
+    let (_, target) = Target::split(roll);
+    let dn = target.map(|target| u64::try_from(target.value).unwrap_or(0));
+    let rolled = pool.roll(rng);
+    let icons = i64::try_from(rolled.icons()).unwrap_or(i64::MAX);
+
+    let mut dice_roll = DiceRoll::new(roll, rolled.render(dn), Some(icons));
+    dice_roll.verdict = target.map(|target| target.check(icons, None));
+    dice_roll
+}
+
+/// rolls a blades in the dark action and reads its outcome tier
+fn roll_action<R: Rng>(roll: &str, action: Action, rng: &mut R) -> DiceRoll {
+    let rolled = action.roll(rng);
+    let mut dice_roll = DiceRoll::new(roll, rolled.render(), None);
+    dice_roll.tier = Some(rolled.tier());
+    dice_roll
+}
+
+/// rolls a pbta move and reads its outcome tier
+fn roll_move<R: Rng>(roll: &str, pbta_move: Move, rng: &mut R) -> DiceRoll {
+    let rolled = pbta_move.roll(rng);
+    let mut dice_roll = DiceRoll::new(roll, rolled.render(), Some(rolled.total()));
+    dice_roll.tier = Some(rolled.tier());
+    dice_roll
+}
+
+/// rolls a percentile skill check and reads its success level
+fn roll_percentile<R: Rng>(roll: &str, check: Check, rng: &mut R) -> DiceRoll {
+    let rolled = check.roll(rng);
+    let value = i64::try_from(rolled.value()).unwrap_or(i64::MAX);
+    let mut dice_roll = DiceRoll::new(roll, rolled.render(), Some(value));
+    dice_roll.verdict = Some(rolled.verdict());
+    dice_roll
+}
+
+/// checks the expression against the limits and rolls it on a blocking thread,
+/// giving up if it takes too long
+///
+/// the blocking thread cannot be stopped from outside, so it checks the same deadline between
+/// parts and repeated rolls and stops by itself. a single roll is not interrupted, the limits
+/// keep it short
+pub async fn roll_dice_guarded<R: Rng + Send + 'static>(
+    expr: &str,
+    system: &'static dyn GameSystem,
+    mut rng: R,
+    limits: Limits,
+) -> Result<Vec<DiceRoll>> {
+    limits.check(expr)?;
+
+    let expr = expr.to_string();
+    let deadline = Instant::now() + ROLL_TIMEOUT;
+    let task = tokio::task::spawn_blocking(move || roll_dice(&expr, system, &mut rng, deadline));
+    match tokio::time::timeout(ROLL_TIMEOUT, task).await {
+        Ok(rolls) => rolls?,
+        Err(_) => Err(format!("evaluation took longer than {}s", ROLL_TIMEOUT.as_secs()).into()),
+    }
+}
+
+/// rolls a single expression and returns its normalized result, total
+/// and the faces rolled for each of its dice terms
+fn roll_single<R: Rng>(roll: &str, rng: &mut R) -> Result<(String, Option<i64>, Vec<Dice>)> {
+    let roller = Roller::new(roll)?;
+    let result = roller
+        .roll_with(rng)
+        .map_err(|e| format!("error while parsing input: {roll}\n```\n{e}\n```"))?;
+
+    let single = result.as_single();
+    let total = single.map(caith::SingleRollResult::get_total);
+    let rolled = single
+        .map(|single| {
+            single
+                .get_history()
+                .iter()
+                .filter_map(|history| match history {
+                    caith::RollHistory::Roll(dice) => {
+                        Some(dice.iter().map(|die| die.res).collect::<Vec<_>>())
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let dice = history_dice(roll, rolled);
+    Ok((
+        normalize_dice_expr(result.to_string().as_ref()),
+        total,
+        dice,
+    ))
+}
+
+/// turns the dice rolled in the history of a roll into [`Dice`]
+///
+/// the history only holds the faces, so the sides are read from the dice terms of the
+/// expression. if the two do not line up, no dice are returned rather than the faces of one
+/// die being read as those of another
+fn history_dice(roll: &str, rolled: Vec<Vec<u64>>) -> Vec<Dice> {
+    let terms = dice_terms(roll);
+    let lined_up = terms.len() == rolled.len()
+        && terms.iter().zip(&rolled).all(|((_, sides), faces)| {
+            !faces.is_empty() && faces.iter().all(|face| (1..=*sides).contains(face))
+        });
+    if !lined_up {
+        return Vec::new();
+    }
+    terms
+        .into_iter()
+        .zip(rolled)
+        .map(|((_, sides), faces)| Dice { sides, faces })
         .collect()
 }
+
+/// returns an error once the deadline of an evaluation has passed
+fn check_deadline(deadline: Instant) -> Result<()> {
+    if Instant::now() > deadline {
+        return Err(format!("evaluation took longer than {}s", ROLL_TIMEOUT.as_secs()).into());
+    }
+    Ok(())
+}
+
+/// rolls a repeated expression and renders the results as a table with its sum
+fn roll_repeat<R: Rng>(
+    repeat: Repeat<'_>,
+    rng: &mut R,
+    deadline: Instant,
+) -> Result<(String, Option<i64>)> {
+    let mut table = Table::new();
+    for _ in 0..repeat.count {
+        check_deadline(deadline)?;
+        let (result, total, _) = roll_single(repeat.expr, rng)?;
+        let total =
+            total.ok_or_else(|| format!("cannot repeat {}: it has no total", repeat.expr))?;
+        table.push(result, total);
+    }
+    Ok((table.render(EMBED_DESCRIPTION_LIMIT), Some(table.sum())))
+}
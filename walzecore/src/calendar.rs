@@ -0,0 +1,264 @@
+use std::fmt::Write;
+
+use chrono::{DateTime, Duration, Utc};
+use sha2::{Digest, Sha256};
+
+/// Length of an event when none is given.
+pub const DEFAULT_DURATION: Duration = Duration::hours(1);
+
+/// Lines of an iCalendar file are folded after this many octets.
+const MAX_LINE_OCTETS: usize = 75;
+
+const ICS_DATE_TIME: &str = "%Y%m%dT%H%M%SZ";
+
+/// A calendar event that can be exported as an iCalendar file or a link to add it to a
+/// calendar provider.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use walzecore::calendar::Event;
+///
+/// let start = Utc.with_ymd_and_hms(2026, 11, 2, 18, 0, 0).unwrap();
+/// let mut event = Event::new("Session 12", start);
+/// event.duration = Duration::minutes(150);
+/// event.location = Some("Roll20 & Discord".into());
+///
+/// assert_eq!(event.end().to_rfc3339(), "2026-11-02T20:30:00+00:00");
+/// assert_eq!(
+///     event.google_link(),
+///     "https://calendar.google.com/calendar/render?action=TEMPLATE&text=Session%2012\
+///      &dates=20261102T180000Z%2F20261102T203000Z&location=Roll20%20%26%20Discord"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub title: String,
+    pub start: DateTime<Utc>,
+    pub duration: Duration,
+    pub description: Option<String>,
+    pub location: Option<String>,
+}
+
+impl Event {
+    /// Creates an event lasting [`DEFAULT_DURATION`] without description or location.
+    pub fn new<T: Into<String>>(title: T, start: DateTime<Utc>) -> Self {
+        Self {
+            title: title.into(),
+            start,
+            duration: DEFAULT_DURATION,
+            description: None,
+            location: None,
+        }
+    }
+
+    /// Returns when the event ends.
+    pub fn end(&self) -> DateTime<Utc> {
+        self.start + self.duration
+    }
+
+    /// Returns an identifier that stays the same for the same title and start, so importing
+    /// the event twice updates it instead of adding it again.
+    pub fn uid(&self) -> String {
+        let digest = Sha256::digest(format!("{}:{}", self.title, self.start.timestamp()));
+        let hex: String = digest[..16].iter().map(|b| format!("{b:02x}")).collect();
+        format!("{hex}@walze")
+    }
+
+    /// Renders the event as an iCalendar (RFC 5545) file, stamped as created at `now`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use walzecore::calendar::Event;
+    ///
+    /// let start = Utc.with_ymd_and_hms(2026, 11, 2, 18, 0, 0).unwrap();
+    /// let mut event = Event::new("Session 12; the heist", start);
+    /// event.description = Some("Bring dice,\nand snacks".into());
+    ///
+    /// let ics = event.to_ics(start);
+    /// assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+    /// assert!(ics.contains("\r\nDTSTART:20261102T180000Z\r\nDTEND:20261102T190000Z\r\n"));
+    /// assert!(ics.contains("\r\nSUMMARY:Session 12\\; the heist\r\n"));
+    /// assert!(ics.contains("\r\nDESCRIPTION:Bring dice\\,\\nand snacks\r\n"));
+    /// assert!(ics.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+    ///
+    /// // long lines are folded
+    /// event.description = Some("a".repeat(100));
+    /// assert!(event.to_ics(start).lines().all(|line| line.len() <= 75));
+    /// ```
+    pub fn to_ics(&self, now: DateTime<Utc>) -> String {
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            "PRODID:-//walze//walze//EN".to_string(),
+            "CALSCALE:GREGORIAN".to_string(),
+            "METHOD:PUBLISH".to_string(),
+            "BEGIN:VEVENT".to_string(),
+            format!("UID:{}", self.uid()),
+            format!("DTSTAMP:{}", now.format(ICS_DATE_TIME)),
+            format!("DTSTART:{}", self.start.format(ICS_DATE_TIME)),
+            format!("DTEND:{}", self.end().format(ICS_DATE_TIME)),
+            format!("SUMMARY:{}", escape_text(&self.title)),
+        ];
+        if let Some(description) = &self.description {
+            lines.push(format!("DESCRIPTION:{}", escape_text(description)));
+        }
+        if let Some(location) = &self.location {
+            lines.push(format!("LOCATION:{}", escape_text(location)));
+        }
+        lines.push("END:VEVENT".to_string());
+        lines.push("END:VCALENDAR".to_string());
+
+        lines.iter().fold(String::new(), |mut ics, line| {
+            ics.push_str(&fold_line(line));
+            ics.push_str("\r\n");
+            ics
+        })
+    }
+
+    /// Returns a link adding the event to Google Calendar.
+    pub fn google_link(&self) -> String {
+        let dates = format!(
+            "{}/{}",
+            self.start.format(ICS_DATE_TIME),
+            self.end().format(ICS_DATE_TIME)
+        );
+        link(
+            "https://calendar.google.com/calendar/render?action=TEMPLATE",
+            &[
+                ("text", Some(self.title.as_str())),
+                ("dates", Some(dates.as_str())),
+                ("details", self.description.as_deref()),
+                ("location", self.location.as_deref()),
+            ],
+        )
+    }
+
+    /// Returns a link adding the event to Outlook.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use walzecore::calendar::Event;
+    ///
+    /// let start = Utc.with_ymd_and_hms(2026, 11, 2, 18, 0, 0).unwrap();
+    /// assert_eq!(
+    ///     Event::new("Session", start).outlook_link(),
+    ///     "https://outlook.live.com/calendar/0/action/compose?rru=addevent&subject=Session\
+    ///      &startdt=2026-11-02T18%3A00%3A00Z&enddt=2026-11-02T19%3A00%3A00Z"
+    /// );
+    /// ```
+    pub fn outlook_link(&self) -> String {
+        let format = "%Y-%m-%dT%H:%M:%SZ";
+        let start = self.start.format(format).to_string();
+        let end = self.end().format(format).to_string();
+        link(
+            "https://outlook.live.com/calendar/0/action/compose?rru=addevent",
+            &[
+                ("subject", Some(self.title.as_str())),
+                ("startdt", Some(start.as_str())),
+                ("enddt", Some(end.as_str())),
+                ("body", self.description.as_deref()),
+                ("location", self.location.as_deref()),
+            ],
+        )
+    }
+
+    /// Returns a link adding the event to Yahoo Calendar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use walzecore::calendar::Event;
+    ///
+    /// let start = Utc.with_ymd_and_hms(2026, 11, 2, 18, 0, 0).unwrap();
+    /// let mut event = Event::new("Session", start);
+    /// event.description = Some("Zoë's one-shot".into());
+    /// assert_eq!(
+    ///     event.yahoo_link(),
+    ///     "https://calendar.yahoo.com/?v=60&title=Session&st=20261102T180000Z\
+    ///      &et=20261102T190000Z&desc=Zo%C3%AB%27s%20one-shot"
+    /// );
+    /// ```
+    pub fn yahoo_link(&self) -> String {
+        let start = self.start.format(ICS_DATE_TIME).to_string();
+        let end = self.end().format(ICS_DATE_TIME).to_string();
+        link(
+            "https://calendar.yahoo.com/?v=60",
+            &[
+                ("title", Some(self.title.as_str())),
+                ("st", Some(start.as_str())),
+                ("et", Some(end.as_str())),
+                ("desc", self.description.as_deref()),
+                ("in_loc", self.location.as_deref()),
+            ],
+        )
+    }
+}
+
+/// Percent-encodes everything but the unreserved characters of RFC 3986.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::calendar::encode;
+///
+/// assert_eq!(encode("Dice & Dragons #3"), "Dice%20%26%20Dragons%20%233");
+/// assert_eq!(encode("a-b_c.d~e"), "a-b_c.d~e");
+/// assert_eq!(encode("ü"), "%C3%BC");
+/// ```
+pub fn encode(text: &str) -> String {
+    text.bytes().fold(String::new(), |mut out, byte| {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(char::from(byte));
+            }
+            _ => {
+                let _ = write!(out, "%{byte:02X}");
+            }
+        }
+        out
+    })
+}
+
+/// Appends the given query parameters to a base that already has a query.
+fn link(base: &str, params: &[(&str, Option<&str>)]) -> String {
+    params
+        .iter()
+        .fold(base.to_string(), |mut link, (key, value)| {
+            if let Some(value) = value {
+                let _ = write!(link, "&{key}={}", encode(value));
+            }
+            link
+        })
+}
+
+/// Escapes the characters iCalendar text values cannot contain as they are.
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Folds a content line into lines of at most 75 octets, continued by a leading space,
+/// without splitting a character.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            octets = 1;
+        }
+        folded.push(c);
+        octets += c.len_utf8();
+    }
+    folded
+}
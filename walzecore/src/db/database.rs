@@ -1,10 +1,11 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::convert;
 
 use serde::{Deserialize, Serialize};
 
 use crate::db;
 use crate::db::Result;
+use crate::tz::window::Window;
 
 /// A struct representing a user with namespaces and aliases.
 ///
@@ -27,6 +28,16 @@ use crate::db::Result;
 pub struct User {
     namespace: String,
     alias: HashMap<String, HashMap<String, String>>,
+    #[serde(default)]
+    systems: HashMap<String, String>,
+    #[serde(default)]
+    tiers: HashMap<String, BTreeMap<String, String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    home_zone: Option<String>,
+    #[serde(default)]
+    share_zone: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    availability: Vec<Window>,
 }
 
 impl Default for User {
@@ -53,7 +64,15 @@ impl User {
         let namespace = String::from("default");
         let mut alias = HashMap::new();
         alias.insert(namespace.clone(), HashMap::new());
-        Self { namespace, alias }
+        Self {
+            namespace,
+            alias,
+            systems: HashMap::new(),
+            tiers: HashMap::new(),
+            home_zone: None,
+            share_zone: false,
+            availability: Vec::new(),
+        }
     }
 
     /// Adds a new namespace to the user.
@@ -262,9 +281,150 @@ impl User {
         if self.namespace == ns {
             self.namespace = String::from("default");
         }
+        self.systems.remove(&ns);
+        self.tiers.remove(&ns);
 
         self.alias
             .remove_entry(&ns)
             .ok_or_else(|| db::Error::NamespaceNotFound(ns))
     }
+
+    /// Returns the name of the game system the current namespace is bound to, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::db::database::User;
+    ///
+    /// let mut user = User::new();
+    /// user.add_namespace("w&g");
+    /// user.namespace_mut("w&g");
+    /// assert_eq!(user.system(), None);
+    /// user.system_mut(Some("wrath"));
+    /// assert_eq!(user.system(), Some("wrath"));
+    /// user.namespace_mut("default");
+    /// assert_eq!(user.system(), None);
+    /// ```
+    pub fn system(&self) -> Option<&str> {
+        self.systems.get(&self.namespace).map(String::as_str)
+    }
+
+    /// Binds the current namespace to a game system, or unbinds it when `None` is given.
+    pub fn system_mut<T: Into<String>>(&mut self, system: Option<T>) {
+        match system {
+            Some(system) => {
+                self.systems.insert(self.namespace.clone(), system.into());
+            }
+            None => {
+                self.systems.remove(&self.namespace);
+            }
+        }
+    }
+
+    /// Returns what an outcome tier, given by its name, means in the current namespace, if it
+    /// was described.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::db::database::User;
+    ///
+    /// let mut user = User::new();
+    /// assert_eq!(user.tier_description("partial"), None);
+    /// user.tier_description_mut("partial", Some("devil's bargain"));
+    /// assert_eq!(user.tier_description("partial"), Some("devil's bargain"));
+    /// user.tier_description_mut::<&str>("partial", None);
+    /// assert_eq!(user.tier_description("partial"), None);
+    /// ```
+    pub fn tier_description(&self, tier: &str) -> Option<&str> {
+        self.tiers
+            .get(&self.namespace)
+            .and_then(|tiers| tiers.get(tier))
+            .map(String::as_str)
+    }
+
+    /// Describes an outcome tier in the current namespace, or forgets its description when
+    /// `None` is given.
+    pub fn tier_description_mut<T: Into<String>>(&mut self, tier: &str, description: Option<T>) {
+        match description {
+            Some(description) => {
+                self.tiers
+                    .entry(self.namespace.clone())
+                    .or_default()
+                    .insert(tier.to_string(), description.into());
+            }
+            None => {
+                if let Some(tiers) = self.tiers.get_mut(&self.namespace) {
+                    tiers.remove(tier);
+                }
+            }
+        }
+    }
+
+    /// Returns the user's home timezone, if one is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::db::database::User;
+    ///
+    /// let mut user = User::new();
+    /// assert_eq!(user.home_zone(), None);
+    /// user.home_zone_mut(Some("Europe/Berlin"));
+    /// assert_eq!(user.home_zone(), Some("Europe/Berlin"));
+    /// ```
+    pub fn home_zone(&self) -> Option<&str> {
+        self.home_zone.as_deref()
+    }
+
+    /// Sets the user's home timezone, or clears it when `None` is given.
+    pub fn home_zone_mut<T: Into<String>>(&mut self, zone: Option<T>) {
+        self.home_zone = zone.map(Into::into);
+    }
+
+    /// Returns the user's home timezone if they let others see it.
+    ///
+    /// Home timezones are private until shared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::db::database::User;
+    ///
+    /// let mut user = User::new();
+    /// user.home_zone_mut(Some("Europe/Berlin"));
+    /// assert_eq!(user.shared_zone(), None);
+    /// user.share_zone_mut(true);
+    /// assert_eq!(user.shared_zone(), Some("Europe/Berlin"));
+    /// ```
+    pub fn shared_zone(&self) -> Option<&str> {
+        self.home_zone().filter(|_| self.share_zone)
+    }
+
+    /// Lets others see the user's home timezone, or hides it again.
+    pub fn share_zone_mut(&mut self, share: bool) {
+        self.share_zone = share;
+    }
+
+    /// Returns the weekly windows the user is available in, in their home timezone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::{db::database::User, tz::window::Window};
+    ///
+    /// let mut user = User::new();
+    /// assert!(user.availability().is_empty());
+    /// user.availability_mut(Window::parse_list("fri 19:00-23:00")?);
+    /// assert_eq!(user.availability()[0].to_string(), "Fri 19:00-23:00");
+    /// # Ok::<(), self::walzecore::tz::Error>(())
+    /// ```
+    pub fn availability(&self) -> &[Window] {
+        &self.availability
+    }
+
+    /// Replaces the user's weekly availability windows, clearing them when empty.
+    pub fn availability_mut(&mut self, windows: Vec<Window>) {
+        self.availability = windows;
+    }
 }
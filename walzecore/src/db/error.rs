@@ -16,4 +16,6 @@ pub enum Error {
     Simple(&'static str),
     #[error("{0}")]
     Io(#[from] io::Error),
+    #[error("invalid guild settings: {0}")]
+    InvalidGuilds(#[from] serde_json::Error),
 }
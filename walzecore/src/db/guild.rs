@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+/// A struct representing the settings of a guild.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::db::guild::Guild;
+///
+/// let mut guild = Guild::new();
+/// assert_eq!(guild.gm_role(), None);
+/// guild.gm_role_mut(Some(1234));
+/// assert_eq!(guild.gm_role(), Some(1234));
+/// assert!(!guild.inline_rolls());
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(default)]
+pub struct Guild {
+    gm_role: Option<u64>,
+    inline_rolls: bool,
+}
+
+impl Guild {
+    /// Creates a new `Guild` with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the id of the role whose members receive secret rolls.
+    pub fn gm_role(&self) -> Option<u64> {
+        self.gm_role
+    }
+
+    /// Sets or clears the role whose members receive secret rolls.
+    pub fn gm_role_mut(&mut self, role: Option<u64>) {
+        self.gm_role = role;
+    }
+
+    /// Returns `true` if `[[...]]` rolls in chat messages are answered.
+    pub fn inline_rolls(&self) -> bool {
+        self.inline_rolls
+    }
+
+    /// Enables or disables answering `[[...]]` rolls in chat messages.
+    pub fn inline_rolls_mut(&mut self, enabled: bool) {
+        self.inline_rolls = enabled;
+    }
+}
@@ -1,5 +1,6 @@
 pub mod database;
 pub mod error;
+pub mod guild;
 
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Deserializer, Serialize};
@@ -10,6 +11,7 @@ use std::ops::{Deref, DerefMut};
 pub use error::{Error, Result};
 
 pub use crate::db::database::User;
+pub use crate::db::guild::Guild;
 
 /// A container for storing users, keyed by a hashable and equality-comparable type.
 ///
@@ -131,3 +133,64 @@ where
         Ok(Users { users })
     }
 }
+
+/// A container for storing guild settings, keyed by a hashable and equality-comparable type.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::db::Guilds;
+///
+/// let mut guilds = Guilds::<u64>::new("{}")?;
+/// guilds.get_or_create(1).gm_role_mut(Some(42));
+/// assert_eq!(guilds.get_or_create(1).gm_role(), Some(42));
+/// # Ok::<(), self::walzecore::db::Error>(())
+/// ```
+#[derive(Debug, Default, Serialize)]
+pub struct Guilds<T>
+where
+    T: Hash + Eq + Serialize + DeserializeOwned,
+{
+    guilds: HashMap<T, Guild>,
+}
+
+impl<T: Hash + Eq + Serialize + DeserializeOwned> Guilds<T> {
+    /// Creates a new `Guilds` instance from a JSON string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON cannot be deserialized, so corrupt guild settings are never
+    /// replaced by empty ones and written back over the file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::db::Guilds;
+    ///
+    /// assert!(Guilds::<u64>::new("{}")?.is_empty());
+    /// assert!(Guilds::<u64>::new("{\"1\": 3}").is_err());
+    /// # Ok::<(), self::walzecore::db::Error>(())
+    /// ```
+    pub fn new(json: &str) -> Result<Guilds<T>> {
+        let guilds = serde_json::from_str(json)?;
+        Ok(Guilds { guilds })
+    }
+
+    /// Converts the guilds container to a JSON string.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&self.guilds).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// Get a `Guild` instance for the given `guild_id`, creating a new default instance if it doesn't exist.
+    pub fn get_or_create(&mut self, guild_id: T) -> &mut Guild {
+        self.guilds.entry(guild_id).or_default()
+    }
+}
+
+impl<T: Hash + Eq + Serialize + DeserializeOwned> Deref for Guilds<T> {
+    type Target = HashMap<T, Guild>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.guilds
+    }
+}
@@ -0,0 +1,220 @@
+use std::str::FromStr;
+
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+
+use crate::dice;
+use crate::dice::expr::{dice_terms, split, DICE_REGEX};
+use crate::dice::target::{Natural, Target, Verdict};
+use crate::dice::Result;
+
+lazy_static! {
+    /// Matches `attack(<to hit>, <damage>[, <options>...])`
+    static ref ATTACK_REGEX: Regex = Regex::new(r"^\s*attack\((.*)\)\s*$").unwrap();
+}
+
+/// How damage is increased on a critical hit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CritRule {
+    /// Roll twice the number of damage dice (5e).
+    #[default]
+    DoubleDice,
+    /// Double the rolled damage total.
+    DoubleTotal,
+    /// Add the maximum of the damage dice to the rolled damage.
+    MaxPlusRoll,
+}
+
+impl FromStr for CritRule {
+    type Err = dice::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "5e" | "dice" => Ok(CritRule::DoubleDice),
+            "double" | "total" => Ok(CritRule::DoubleTotal),
+            "max" => Ok(CritRule::MaxPlusRoll),
+            _ => Err(dice::Error::InvalidCritRule(s.trim().to_string())),
+        }
+    }
+}
+
+impl CritRule {
+    /// Rewrites a damage expression for a critical hit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::dice::attack::CritRule;
+    ///
+    /// assert_eq!(CritRule::DoubleDice.apply("2d6+4"), "4d6+4");
+    /// assert_eq!(CritRule::DoubleTotal.apply("2d6+4"), "(2d6+4)*2");
+    /// assert_eq!(CritRule::MaxPlusRoll.apply("2d6+d8+4"), "2d6+d8+4+20");
+    /// ```
+    pub fn apply(self, damage: &str) -> String {
+        match self {
+            CritRule::DoubleDice => DICE_REGEX
+                .replace_all(damage, |caps: &Captures| {
+                    let count: u64 = match &caps[1] {
+                        "" => 1,
+                        count => count.parse().unwrap_or(u64::MAX),
+                    };
+                    format!("{}d{}", count.saturating_mul(2), &caps[2])
+                })
+                .into_owned(),
+            CritRule::DoubleTotal => format!("({damage})*2"),
+            CritRule::MaxPlusRoll => {
+                let max: u64 = dice_terms(damage)
+                    .into_iter()
+                    .map(|(count, sides)| count.saturating_mul(sides))
+                    .sum();
+                format!("{damage}+{max}")
+            }
+        }
+    }
+}
+
+/// An attack macro, `attack(1d20+7, 2d6+4)`, that rolls to hit and then damage.
+///
+/// Options may follow the damage: a [`CritRule`] name (`5e`, `double`, `max`) and the lowest
+/// natural roll that is a critical hit, e.g. `attack(1d20+7 vs 15, 1d8+4, 19)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Attack<'a> {
+    pub to_hit: &'a str,
+    pub damage: &'a str,
+    pub rule: CritRule,
+    /// Lowest natural roll of the to-hit die that is a critical hit.
+    pub crit_range: u64,
+}
+
+/// What the to-hit roll of an [`Attack`] amounted to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttackOutcome {
+    pub natural: Option<Natural>,
+    /// The outcome against the target, if the to-hit roll had one.
+    pub verdict: Option<Verdict>,
+    /// Whether damage should be rolled.
+    pub hits: bool,
+}
+
+impl AttackOutcome {
+    /// Returns `true` on a critical hit.
+    pub fn is_critical(&self) -> bool {
+        self.natural == Some(Natural::Max)
+    }
+}
+
+impl<'a> Attack<'a> {
+    /// Parses an attack macro.
+    ///
+    /// Returns `Ok(None)` if the expression is not an attack macro.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the damage is missing or an option is not understood.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::dice::attack::{Attack, CritRule};
+    ///
+    /// let attack = Attack::parse("attack(1d20+7, 2d6+4)")?.unwrap();
+    /// assert_eq!((attack.to_hit, attack.damage), ("1d20+7", "2d6+4"));
+    /// assert_eq!((attack.rule, attack.crit_range), (CritRule::DoubleDice, 20));
+    ///
+    /// let attack = Attack::parse("attack(1d20+7 vs 15, 1d8+4, max, 19)")?.unwrap();
+    /// assert_eq!((attack.rule, attack.crit_range), (CritRule::MaxPlusRoll, 19));
+    ///
+    /// assert_eq!(Attack::parse("1d20+7")?, None);
+    /// # Ok::<(), self::walzecore::dice::Error>(())
+    /// ```
+    pub fn parse(expr: &'a str) -> Result<Option<Attack<'a>>> {
+        let Some(args) = ATTACK_REGEX.captures(expr).and_then(|caps| caps.get(1)) else {
+            return Ok(None);
+        };
+        let args = split(args.as_str());
+        let [to_hit, damage, options @ ..] = args.as_slice() else {
+            return Err(dice::Error::Simple(
+                "an attack needs a to hit roll and a damage roll: attack(1d20+7, 2d6+4)",
+            ));
+        };
+
+        let mut attack = Attack {
+            to_hit,
+            damage,
+            rule: CritRule::default(),
+            crit_range: 20,
+        };
+        for option in options {
+            match option.parse::<u64>() {
+                Ok(range) => attack.crit_range = range,
+                Err(_) => attack.rule = option.parse()?,
+            }
+        }
+
+        Ok(Some(attack))
+    }
+
+    /// Resolves the to-hit roll from its total and the face of its die.
+    ///
+    /// A natural roll within the crit range always hits and a natural 1 always misses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::dice::attack::Attack;
+    /// use walzecore::dice::target::Natural;
+    ///
+    /// let attack = Attack::parse("attack(1d20+7 vs 30, 2d6+4, 19)")?.unwrap();
+    ///
+    /// let outcome = attack.resolve(26, Some(19));
+    /// assert!(outcome.hits && outcome.is_critical());
+    ///
+    /// let outcome = attack.resolve(25, Some(18));
+    /// assert!(!outcome.hits);
+    ///
+    /// let outcome = attack.resolve(8, Some(1));
+    /// assert_eq!((outcome.hits, outcome.natural), (false, Some(Natural::Min)));
+    /// # Ok::<(), self::walzecore::dice::Error>(())
+    /// ```
+    pub fn resolve(&self, total: i64, face: Option<u64>) -> AttackOutcome {
+        let natural = face.and_then(|face| match face {
+            1 => Some(Natural::Min),
+            f if f >= self.crit_range => Some(Natural::Max),
+            _ => None,
+        });
+
+        let (_, target) = Target::split(self.to_hit);
+        let verdict = target.map(|target| {
+            let mut verdict = target.check(total, None);
+            verdict.natural = natural;
+            verdict.face = natural.and(face);
+            match natural {
+                Some(Natural::Max) => verdict.success = true,
+                Some(Natural::Min) => verdict.success = false,
+                None => {}
+            }
+            verdict
+        });
+
+        let hits = match natural {
+            Some(Natural::Max) => true,
+            Some(Natural::Min) => false,
+            None => verdict.map(|verdict| verdict.success).unwrap_or(true),
+        };
+
+        AttackOutcome {
+            natural,
+            verdict,
+            hits,
+        }
+    }
+
+    /// Returns the damage expression to roll, rewritten by the crit rule on a critical hit.
+    pub fn damage(&self, outcome: &AttackOutcome) -> String {
+        if outcome.is_critical() {
+            self.rule.apply(self.damage)
+        } else {
+            self.damage.to_string()
+        }
+    }
+}
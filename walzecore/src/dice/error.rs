@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+use std::result;
+
+pub type Result<T> = result::Result<T, Error>;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("invalid roll id \"{0}\"")]
+    InvalidRollId(String),
+    #[error("roll \"{0}\" not found")]
+    RollNotFound(String),
+    #[error("invalid roll ledger: {0}")]
+    InvalidLedger(#[from] serde_json::Error),
+    #[error("seed for roll \"{0}\" is not revealed yet. commitment: {1}")]
+    SeedNotRevealed(String, String),
+    #[error("cannot repeat a roll {0} times, it must be between 1 and {max}", max = crate::dice::repeat::MAX_REPEAT)]
+    RepeatOutOfRange(String),
+    #[error("expression exceeds the {0} limit of {1}")]
+    LimitExceeded(&'static str, String),
+    #[error("unknown crit rule \"{0}\", expected one of 5e, double, max")]
+    InvalidCritRule(String),
+    #[error("unknown game system \"{0}\"")]
+    UnknownSystem(String),
+    #[error("unknown outcome tier \"{0}\", expected one of failure, partial, success, critical")]
+    UnknownTier(String),
+    #[error("skill value \"{0}\" is not a number, is its alias defined?")]
+    InvalidSkill(String),
+    #[error("{0}")]
+    Simple(&'static str),
+}
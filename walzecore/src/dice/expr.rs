@@ -0,0 +1,340 @@
+use std::ops::Range;
+
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+
+lazy_static! {
+    /// Matches a dice term with its count and sides, e.g. `1d20` or `d20`, but not a drop
+    /// modifier following another term, the `d1` of `4d6d1`
+    pub(crate) static ref DICE_REGEX: Regex = Regex::new(r"\b([0-9]*)d([0-9]+)").unwrap();
+    /// Matches a keep highest or lowest one modifier right after a dice term, `K1` or `k1`
+    static ref KEEP_ONE_REGEX: Regex = Regex::new(r"^([Kk])1(?:[^0-9]|$)").unwrap();
+    /// Matches `[[1d20+5]]` inside a chat message
+    static ref INLINE_REGEX: Regex = Regex::new(r"\[\[([^\[\]]+)\]\]").unwrap();
+    /// Matches `1d20+7 # to hit`
+    static ref COMMENT_LABEL_REGEX: Regex =
+        Regex::new(r"^\s*(?P<expr>.*?)\s*#\s*(?P<label>.+?)\s*$").unwrap();
+    /// Matches `attack: 1d20+7`
+    static ref PREFIX_LABEL_REGEX: Regex =
+        Regex::new(r"^\s*(?P<label>[^:#()]+?)\s*:\s*(?P<expr>.+?)\s*$").unwrap();
+    /// Matches a dice formula, `1d8 + 3`, or an alias, `$stealth`, in free text
+    static ref FORMULA_REGEX: Regex = Regex::new(
+        r"\b[0-9]*d[0-9]+(?:[a-z!]+[0-9]*)*(?:\s*[-+*/]\s*(?:[0-9]*d[0-9]+(?:[a-z!]+[0-9]*)*|[0-9]+\b))*|\$[\w-]+"
+    )
+    .unwrap();
+}
+
+/// Maximum number of words of the surrounding text used to label a found roll.
+const MAX_LABEL_WORDS: usize = 6;
+
+/// Whether a d20 is rolled twice keeping the higher or the lower result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Advantage {
+    Advantage,
+    Disadvantage,
+}
+
+impl Advantage {
+    fn term(self) -> &'static str {
+        match self {
+            Advantage::Advantage => "2d20K1",
+            Advantage::Disadvantage => "2d20k1",
+        }
+    }
+}
+
+/// Rewrites every single d20 term of an expression to be rolled with advantage or disadvantage,
+/// keeping the highest, `2d20K1`, or the lowest, `2d20k1`, of two d20.
+///
+/// Pools of several d20 and inline labels are left untouched.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::expr::{with_advantage, Advantage};
+///
+/// assert_eq!(with_advantage("1d20+5, 2d6", Advantage::Advantage), "2d20K1+5, 2d6");
+/// assert_eq!(with_advantage("d20 - 1", Advantage::Disadvantage), "2d20k1 - 1");
+/// assert_eq!(with_advantage("3d20", Advantage::Advantage), "3d20");
+/// assert_eq!(with_advantage("1d20,1d20", Advantage::Advantage), "2d20K1,2d20K1");
+/// assert_eq!(
+///     with_advantage("attack d20: 1d20+5, 1d20 # d20 check", Advantage::Advantage),
+///     "attack d20: 2d20K1+5, 2d20K1 # d20 check"
+/// );
+/// ```
+pub fn with_advantage(expr: &str, advantage: Advantage) -> String {
+    let labels: Vec<Range<usize>> = split(expr)
+        .into_iter()
+        .filter_map(|part| split_label(part).0)
+        .map(|label| range_in(expr, label))
+        .collect();
+    DICE_REGEX
+        .replace_all(expr, |caps: &Captures| {
+            let start = caps.get(0).map_or(0, |m| m.start());
+            if is_single_d20(caps) && !labels.iter().any(|label| label.contains(&start)) {
+                advantage.term().to_string()
+            } else {
+                caps[0].to_string()
+            }
+        })
+        .into_owned()
+}
+
+/// Returns `true` if the expression contains a single d20 term.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::expr::has_d20;
+///
+/// assert!(has_d20("1d20+7"));
+/// assert!(!has_d20("2d6+4"));
+/// ```
+pub fn has_d20(expr: &str) -> bool {
+    DICE_REGEX
+        .captures_iter(expr)
+        .any(|caps| is_single_d20(&caps))
+}
+
+/// Returns the byte range of `inner`, a slice of `outer`, within `outer`.
+fn range_in(outer: &str, inner: &str) -> Range<usize> {
+    let start = inner.as_ptr() as usize - outer.as_ptr() as usize;
+    start..start + inner.len()
+}
+
+fn is_single_d20(caps: &Captures) -> bool {
+    matches!(&caps[1], "" | "1") && &caps[2] == "20"
+}
+
+/// Returns the number of sides of the die driving the roll, if the first dice term of the
+/// expression is a single die or a pool keeping only its highest or lowest die.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::expr::leading_die;
+///
+/// assert_eq!(leading_die("1d20+7"), Some(20));
+/// assert_eq!(leading_die("d100"), Some(100));
+/// assert_eq!(leading_die("2d20K1+7"), Some(20));
+/// assert_eq!(leading_die("2d6+4"), None);
+/// assert_eq!(leading_die("2d20K10"), None);
+/// ```
+pub fn leading_die(expr: &str) -> Option<u64> {
+    lead(expr).map(|(sides, _)| sides)
+}
+
+/// Returns the natural result of the die driving the roll from the faces rolled for the first
+/// dice term: the face of a single die, or the kept face of a pool keeping one die.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::expr::natural_face;
+///
+/// assert_eq!(natural_face("1d20+7", &[13]), Some(13));
+/// assert_eq!(natural_face("2d20K1+7", &[4, 20]), Some(20));
+/// assert_eq!(natural_face("2d20k1+7", &[4, 20]), Some(4));
+/// assert_eq!(natural_face("2d20+7", &[4, 20]), None);
+/// ```
+pub fn natural_face(expr: &str, faces: &[u64]) -> Option<u64> {
+    let mut faces = faces.iter().copied();
+    match lead(expr)?.1 {
+        Kept::Single => faces.next(),
+        Kept::Highest => faces.max(),
+        Kept::Lowest => faces.min(),
+    }
+}
+
+/// Which face of the leading dice term is its natural result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kept {
+    Single,
+    Highest,
+    Lowest,
+}
+
+/// Returns the sides of the leading dice term and which of its faces is kept, if it is a
+/// single die or keeps one die.
+fn lead(expr: &str) -> Option<(u64, Kept)> {
+    let caps = DICE_REGEX.captures(expr)?;
+    let sides = caps[2].parse().ok()?;
+    if matches!(&caps[1], "" | "1") {
+        return Some((sides, Kept::Single));
+    }
+    let rest = &expr[caps.get(0)?.end()..];
+    let kept = match &KEEP_ONE_REGEX.captures(rest)?[1] {
+        "K" => Kept::Highest,
+        _ => Kept::Lowest,
+    };
+    Some((sides, kept))
+}
+
+/// Splits an inline label off a part of an expression.
+///
+/// Labels are written either as a trailing comment, `1d20+7 # to hit`, or as a prefix,
+/// `attack: 1d20+7`.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::expr::split_label;
+///
+/// assert_eq!(split_label("1d20+7 # to hit"), (Some("to hit"), "1d20+7"));
+/// assert_eq!(split_label("attack: 1d20+7"), (Some("attack"), "1d20+7"));
+/// assert_eq!(split_label("2d6+4"), (None, "2d6+4"));
+/// ```
+pub fn split_label(part: &str) -> (Option<&str>, &str) {
+    if let Some(caps) = COMMENT_LABEL_REGEX
+        .captures(part)
+        .or_else(|| PREFIX_LABEL_REGEX.captures(part))
+    {
+        let label = caps.name("label").map(|m| m.as_str());
+        let expr = caps.name("expr").map_or(part, |m| m.as_str());
+        return (label, expr);
+    }
+    (None, part.trim())
+}
+
+/// Splits an expression into its comma separated parts, ignoring commas inside parentheses.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::expr::split;
+///
+/// assert_eq!(split("1d20+7, 2d6+4,"), vec!["1d20+7", "2d6+4"]);
+/// assert_eq!(split("repeat(6, 4d6K3), 1d4"), vec!["repeat(6, 4d6K3)", "1d4"]);
+/// ```
+pub fn split(expr: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in expr.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&expr[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&expr[start..]);
+
+    parts
+        .into_iter()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Returns the count and number of sides of every dice term in the expression.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::expr::dice_terms;
+///
+/// assert_eq!(dice_terms("4d6K3 + d8 + 2"), vec![(4, 6), (1, 8)]);
+/// assert_eq!(dice_terms("4d6d1 + 2d20K1d1"), vec![(4, 6), (2, 20)]);
+/// ```
+pub fn dice_terms(expr: &str) -> Vec<(u64, u64)> {
+    DICE_REGEX
+        .captures_iter(expr)
+        .map(|caps| {
+            let count = match &caps[1] {
+                "" => 1,
+                count => count.parse().unwrap_or(u64::MAX),
+            };
+            (count, caps[2].parse().unwrap_or(u64::MAX))
+        })
+        .collect()
+}
+
+/// Returns every inline roll, `[[1d20+5]]`, found in a chat message.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::expr::inline_rolls;
+///
+/// let message = "I attack [[1d20+5]] and deal [[$dmg]] damage";
+/// assert_eq!(inline_rolls(message), vec!["1d20+5", "$dmg"]);
+/// assert!(inline_rolls("[[ ]] and [not a roll]").is_empty());
+/// ```
+pub fn inline_rolls(message: &str) -> Vec<&str> {
+    INLINE_REGEX
+        .captures_iter(message)
+        .filter_map(|caps| caps.get(1))
+        .map(|m| m.as_str().trim())
+        .filter(|roll| !roll.is_empty())
+        .collect()
+}
+
+/// Finds every dice formula and known alias in free text, such as a pasted stat block, and
+/// labels each with the text surrounding it.
+///
+/// The label is made of the heading of the line, the text before its first `.` or `:`, and the
+/// clause right in front of the roll. Labels only contain words, so they can be written in front
+/// of the roll as `label: roll`.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::expr::find_rolls;
+///
+/// let block = "Longsword. Melee Weapon Attack: +5 to hit. Hit: 7 (1d8 + 3) slashing damage.
+/// Fireball 8d6
+/// Sneaky! $stealth and $unknown";
+/// assert_eq!(
+///     find_rolls(block, &["$stealth"]),
+///     vec![
+///         (Some("Longsword Hit".to_string()), "1d8 + 3"),
+///         (Some("Fireball".to_string()), "8d6"),
+///         (Some("Sneaky".to_string()), "$stealth"),
+///     ]
+/// );
+/// assert_eq!(find_rolls("roll 1d20+5, then 2d6", &[])[1], (Some("then".to_string()), "2d6"));
+/// assert!(find_rolls("no dice in here", &[]).is_empty());
+/// ```
+pub fn find_rolls<'a>(text: &'a str, aliases: &[&str]) -> Vec<(Option<String>, &'a str)> {
+    let mut rolls = Vec::new();
+    for line in text.lines() {
+        let heading = line.find(['.', ':']).map(|end| (end, words(&line[..end])));
+        let mut prev_end = 0;
+        for m in FORMULA_REGEX.find_iter(line) {
+            if m.as_str().starts_with('$') && !aliases.contains(&m.as_str()) {
+                continue;
+            }
+
+            let clause = line[prev_end..m.start()]
+                .rsplit(['.', ';', ',', '(', ')', '[', ']'])
+                .map(words)
+                .find(|words| !words.is_empty())
+                .unwrap_or_default();
+            let mut label: Vec<&str> = match &heading {
+                Some((end, heading)) if *end < m.start() && !clause.starts_with(heading) => {
+                    heading.iter().chain(&clause).copied().collect()
+                }
+                _ => clause,
+            };
+            if label.len() > MAX_LABEL_WORDS {
+                label.drain(..label.len() - MAX_LABEL_WORDS);
+            }
+
+            let label = (!label.is_empty()).then(|| label.join(" "));
+            rolls.push((label, m.as_str()));
+            prev_end = m.end();
+        }
+    }
+    rolls
+}
+
+/// Returns the words of a piece of text that contain at least one letter.
+fn words(text: &str) -> Vec<&str> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '\'' || c == '-'))
+        .filter(|word| word.chars().any(char::is_alphabetic))
+        .collect()
+}
@@ -0,0 +1,171 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::dice;
+use crate::dice::attack::Attack;
+use crate::dice::expr::{dice_terms, split, split_label};
+use crate::dice::repeat::Repeat;
+use crate::dice::system::{blades::Action, pbta::Move, percentile::Check, wrath::Pool};
+use crate::dice::target::Target;
+use crate::dice::Result;
+
+lazy_static! {
+    /// Matches a dice term with the modifiers that follow it, e.g. `6d10ir2` or `4d6K3ie`
+    static ref MODIFIED_TERM_REGEX: Regex =
+        Regex::new(r"\b[0-9]*d([0-9]+)((?:[A-Za-z]+[0-9]*)*)").unwrap();
+    /// Matches an indefinite explode or reroll modifier with its threshold, `ie6` or `ir1`
+    static ref INDEFINITE_REGEX: Regex = Regex::new(r"i([er])([0-9]*)").unwrap();
+}
+
+/// Caps on what a dice expression may ask for, checked before it is evaluated.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::limits::Limits;
+///
+/// let limits = Limits::default();
+/// assert!(limits.check("1d20+7, 2d6+4").is_ok());
+/// assert!(limits.check("999999d999999").is_err());
+/// assert!(limits.check("20x 100d6").is_err());
+/// assert!(limits.check("wrath(5000) vs 3").is_err());
+/// assert!(limits.check("blades(2000)").is_err());
+/// // the damage is checked as rolled on a critical hit
+/// assert!(limits.check("attack(1d20+7, 499d6)").is_ok());
+/// assert!(limits.check("attack(1d20+7, 500d6)").is_err());
+///
+/// // indefinite explosions and rerolls have to be able to stop
+/// assert!(limits.check("6d10ie, 6d10ir2").is_ok());
+/// assert!(limits.check("1d1ie").is_err());
+/// assert!(limits.check("1d6ie1").is_err());
+/// assert!(limits.check("1d1ir1").is_err());
+/// assert!(limits.check("1d6ir6").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Limits {
+    /// Total number of dice across the whole expression, repetitions included.
+    pub max_dice: u64,
+    /// Number of sides of a single die.
+    pub max_sides: u64,
+    /// Number of explosion markers (`!`) in a single part.
+    pub max_explosions: usize,
+    /// Number of comma separated parts.
+    pub max_parts: usize,
+    /// Length of the expression in bytes.
+    pub max_length: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_dice: 1000,
+            max_sides: 10_000,
+            max_explosions: 2,
+            max_parts: 25,
+            max_length: 1000,
+        }
+    }
+}
+
+impl Limits {
+    /// Checks an expression against the limits without evaluating it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`dice::Error::LimitExceeded`] naming the first limit the expression exceeds.
+    pub fn check(&self, expr: &str) -> Result<()> {
+        if expr.len() > self.max_length {
+            return Err(exceeded("expression length", self.max_length));
+        }
+
+        let parts = split(expr);
+        if parts.len() > self.max_parts {
+            return Err(exceeded("number of rolls", self.max_parts));
+        }
+
+        let mut total_dice = 0u64;
+        for part in parts {
+            let (_, part) = split_label(part);
+            let (times, part) = match Repeat::parse(part)? {
+                Some(repeat) => (repeat.count as u64, repeat.expr),
+                None => (1, part),
+            };
+
+            // a critical hit rolls the damage as rewritten by the crit rule
+            let critical;
+            let part = match Attack::parse(part)? {
+                Some(attack) => {
+                    critical = format!("{}, {}", attack.to_hit, attack.rule.apply(attack.damage));
+                    critical.as_str()
+                }
+                None => part,
+            };
+
+            let pool = if let Some(pool) = Pool::parse(Target::split(part).0)? {
+                Some(pool.dice)
+            } else if let Some(action) = Action::parse(part) {
+                Some(action.rolled_dice())
+            } else if let Some(check) = Check::parse(part)? {
+                Some(check.tens_dice() + 1)
+            } else {
+                Move::parse(part).map(|_| 2)
+            };
+            if let Some(dice) = pool {
+                total_dice = total_dice.saturating_add(dice.saturating_mul(times));
+                continue;
+            }
+
+            let explosions = part.matches('!').count();
+            if explosions > self.max_explosions {
+                return Err(exceeded("explosion depth", self.max_explosions));
+            }
+
+            check_indefinite(part)?;
+
+            for (count, sides) in dice_terms(part) {
+                if sides > self.max_sides {
+                    return Err(exceeded("number of sides", self.max_sides));
+                }
+                if explosions > 0 && sides < 2 {
+                    return Err(dice::Error::Simple(
+                        "cannot explode dice with less than 2 sides",
+                    ));
+                }
+                total_dice = total_dice.saturating_add(count.saturating_mul(times));
+            }
+        }
+
+        if total_dice > self.max_dice {
+            return Err(exceeded("total dice", self.max_dice));
+        }
+        Ok(())
+    }
+}
+
+/// Rejects indefinite explosions (`ie`) and rerolls (`ir`) that every face of the die would
+/// trigger, as those never stop.
+fn check_indefinite(part: &str) -> Result<()> {
+    for term in MODIFIED_TERM_REGEX.captures_iter(part) {
+        let sides: u64 = term[1].parse().unwrap_or(u64::MAX);
+        for modifier in INDEFINITE_REGEX.captures_iter(&term[2]) {
+            let threshold = modifier[2].parse().ok();
+            let endless = match &modifier[1] {
+                // explodes on the threshold or higher, the highest face by default
+                "e" => threshold.unwrap_or(sides) <= 1,
+                // rerolls on the threshold or lower
+                _ => threshold.unwrap_or(1) >= sides,
+            };
+            if endless {
+                return Err(dice::Error::Simple(
+                    "cannot explode or reroll indefinitely on every face of a die",
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn exceeded<T: ToString>(limit: &'static str, max: T) -> dice::Error {
+    dice::Error::LimitExceeded(limit, max.to_string())
+}
@@ -0,0 +1,14 @@
+pub mod attack;
+pub mod error;
+pub mod expr;
+pub mod limits;
+pub mod repeat;
+pub mod roll;
+pub mod seed;
+pub mod system;
+pub mod target;
+
+pub use error::{Error, Result};
+pub use roll::DiceRoll;
+pub use seed::{Ledger, RollId, RollRecord};
+pub use system::GameSystem;
@@ -0,0 +1,136 @@
+use std::fmt::Write;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::dice;
+use crate::dice::Result;
+
+/// Maximum number of repetitions accepted by a repeat expression.
+pub const MAX_REPEAT: usize = 100;
+
+lazy_static! {
+    /// Matches `6x 4d6K3` and `6x4d6K3`
+    static ref TIMES_REGEX: Regex = Regex::new(r"^\s*([0-9]+)\s*x\s*(.+?)\s*$").unwrap();
+    /// Matches `repeat(6, 4d6K3)`
+    static ref REPEAT_REGEX: Regex =
+        Regex::new(r"^\s*repeat\(\s*([0-9]+)\s*,\s*(.+?)\s*\)\s*$").unwrap();
+}
+
+/// An expression to be rolled several times, e.g. `6x 4d6K3` or `repeat(6, 4d6K3)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Repeat<'a> {
+    pub count: usize,
+    pub expr: &'a str,
+}
+
+impl<'a> Repeat<'a> {
+    /// Parses a repeat expression.
+    ///
+    /// Returns `Ok(None)` if the expression is not a repeat expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repetition count is zero or larger than [`MAX_REPEAT`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::dice::repeat::Repeat;
+    ///
+    /// let repeat = Repeat::parse("6x 4d6K3")?.unwrap();
+    /// assert_eq!((repeat.count, repeat.expr), (6, "4d6K3"));
+    /// let repeat = Repeat::parse("6x4d6K3")?.unwrap();
+    /// assert_eq!((repeat.count, repeat.expr), (6, "4d6K3"));
+    /// let repeat = Repeat::parse("repeat(3, 1d20 + 2)")?.unwrap();
+    /// assert_eq!((repeat.count, repeat.expr), (3, "1d20 + 2"));
+    /// assert_eq!(Repeat::parse("4d6K3")?, None);
+    /// assert!(Repeat::parse("1000x 1d6").is_err());
+    /// # Ok::<(), self::walzecore::dice::Error>(())
+    /// ```
+    pub fn parse(expr: &'a str) -> Result<Option<Repeat<'a>>> {
+        let Some(caps) = TIMES_REGEX
+            .captures(expr)
+            .or_else(|| REPEAT_REGEX.captures(expr))
+        else {
+            return Ok(None);
+        };
+
+        let count = caps[1].parse().unwrap_or(usize::MAX);
+        if count == 0 || count > MAX_REPEAT {
+            return Err(dice::Error::RepeatOutOfRange(caps[1].to_string()));
+        }
+        let expr = caps.get(2).map_or("", |m| m.as_str());
+
+        Ok(Some(Repeat { count, expr }))
+    }
+}
+
+/// The results of a repeated roll, rendered as a compact table with a sum and a sorted list.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::repeat::Table;
+///
+/// let mut table = Table::new();
+/// table.push("[6, 5, 3] = 14", 14);
+/// table.push("[2, 2, 1] = 5", 5);
+/// assert_eq!(table.sum(), 19);
+/// assert_eq!(table.sorted(), vec![14, 5]);
+/// assert!(table.render(4096).ends_with("sum: 19\nsorted: 14, 5\n```"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Table {
+    rows: Vec<(String, i64)>,
+}
+
+impl Table {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the rendered result and total of one repetition.
+    pub fn push<T: Into<String>>(&mut self, result: T, total: i64) {
+        self.rows.push((result.into(), total));
+    }
+
+    /// Returns the sum of all totals.
+    pub fn sum(&self) -> i64 {
+        self.rows.iter().map(|(_, total)| total).sum()
+    }
+
+    /// Returns all totals, highest first.
+    pub fn sorted(&self) -> Vec<i64> {
+        let mut totals: Vec<i64> = self.rows.iter().map(|(_, total)| *total).collect();
+        totals.sort_unstable_by(|a, b| b.cmp(a));
+        totals
+    }
+
+    /// Renders the table inside a code block no longer than `max_len` bytes.
+    ///
+    /// If the detailed rows do not fit, only the totals are listed.
+    pub fn render(&self, max_len: usize) -> String {
+        let sorted = self
+            .sorted()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let summary = format!("sum: {}\nsorted: {sorted}\n```", self.sum());
+
+        let mut table = String::from("```\n");
+        for (i, (result, _)) in self.rows.iter().enumerate() {
+            let _ = writeln!(table, "{:>3} | {result}", i + 1);
+        }
+        if table.len() + summary.len() <= max_len {
+            return table + &summary;
+        }
+
+        let mut table = String::from("```\n");
+        for (i, (_, total)) in self.rows.iter().enumerate() {
+            let _ = writeln!(table, "{:>3} | {total}", i + 1);
+        }
+        table + &summary
+    }
+}
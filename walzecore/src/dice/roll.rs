@@ -0,0 +1,90 @@
+use crate::dice::system::bands::Tier;
+use crate::dice::target::{Natural, Verdict};
+
+/// A rolled part of a dice expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiceRoll {
+    /// The part as it was written, without its label.
+    pub expr: String,
+    /// The inline label of the part, if it had one.
+    pub label: Option<String>,
+    /// The rendered result.
+    pub result: String,
+    /// The total, if the part rolled to a single number.
+    pub total: Option<i64>,
+    /// The outcome against a target, if the part had one.
+    pub verdict: Option<Verdict>,
+    /// A natural maximum or minimum on the die driving the roll.
+    pub natural: Option<Natural>,
+    /// The outcome tier, if the game system reads the roll as one.
+    pub tier: Option<Tier>,
+}
+
+impl DiceRoll {
+    pub fn new<T: Into<String>>(expr: T, result: String, total: Option<i64>) -> Self {
+        Self {
+            expr: expr.into(),
+            label: None,
+            result,
+            total,
+            verdict: None,
+            natural: None,
+            tier: None,
+        }
+    }
+
+    /// Returns the label of the roll, or the expression if it has none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::dice::roll::DiceRoll;
+    ///
+    /// let mut roll = DiceRoll::new("2d6+4", "[3, 5] + 4 = 12".into(), Some(12));
+    /// assert_eq!(roll.label(), "2d6+4");
+    /// roll.label = Some("slashing".into());
+    /// assert_eq!(roll.label(), "slashing");
+    /// ```
+    pub fn label(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.expr)
+    }
+
+    /// Returns `true` if the roll was a critical success.
+    pub fn is_critical(&self) -> bool {
+        if let Some(tier) = self.tier {
+            return tier == Tier::Critical;
+        }
+        match self.verdict {
+            Some(verdict) => verdict.is_critical(),
+            None => self.natural == Some(Natural::Max),
+        }
+    }
+
+    /// Returns the result followed by the verdict, if there is one, or the natural
+    /// maximum or minimum of the roll.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::dice::{roll::DiceRoll, target::{Natural, Target}};
+    ///
+    /// let mut roll = DiceRoll::new("1d20+5 vs 15", "[7] + 5 = 12".into(), Some(12));
+    /// assert_eq!(roll.description(), "[7] + 5 = 12");
+    ///
+    /// let (_, target) = Target::split(&roll.expr);
+    /// roll.verdict = target.map(|target| target.check(12, Some((7, 20))));
+    /// assert_eq!(roll.description(), "[7] + 5 = 12\n**failure by 3**");
+    ///
+    /// let mut roll = DiceRoll::new("1d20", "[20] = 20".into(), Some(20));
+    /// roll.natural = Natural::of(20, 20);
+    /// assert_eq!(roll.description(), "[20] = 20\n**critical!**");
+    /// ```
+    pub fn description(&self) -> String {
+        match (self.verdict, self.natural) {
+            (Some(verdict), _) => format!("{}\n**{verdict}**", self.result),
+            (None, Some(Natural::Max)) => format!("{}\n**critical!**", self.result),
+            (None, Some(Natural::Min)) => format!("{}\n**fumble!**", self.result),
+            (None, None) => self.result.clone(),
+        }
+    }
+}
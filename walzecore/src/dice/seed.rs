@@ -0,0 +1,333 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sha2::{Digest, Sha256};
+
+use crate::dice;
+use crate::dice::Result;
+
+/// Maximum number of roll records kept by a [`Ledger`] before the oldest ones are dropped.
+const MAX_RECORDS: usize = 4096;
+
+/// Number of nonces a [`Ledger`] reserves at once.
+const NONCE_BLOCK: u32 = 64;
+
+/// A short identifier for a roll, made of the seed epoch and a per-epoch nonce.
+///
+/// It is displayed as `<epoch>-<nonce>` in hexadecimal.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::RollId;
+///
+/// let id: RollId = "3-1f".parse()?;
+/// assert_eq!(id.to_string(), "3-1f");
+/// # Ok::<(), self::walzecore::dice::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RollId {
+    epoch: u32,
+    nonce: u32,
+}
+
+impl RollId {
+    /// Returns the seed epoch the roll was made in.
+    pub fn epoch(&self) -> u32 {
+        self.epoch
+    }
+}
+
+impl fmt::Display for RollId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:x}-{:x}", self.epoch, self.nonce)
+    }
+}
+
+impl FromStr for RollId {
+    type Err = dice::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || dice::Error::InvalidRollId(s.to_string());
+        let (epoch, nonce) = s.trim().split_once('-').ok_or_else(invalid)?;
+        Ok(RollId {
+            epoch: u32::from_str_radix(epoch, 16).map_err(|_| invalid())?,
+            nonce: u32::from_str_radix(nonce, 16).map_err(|_| invalid())?,
+        })
+    }
+}
+
+impl Serialize for RollId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for RollId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// What was rolled under a [`RollId`], kept so the roll can be reproduced later.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct RollRecord {
+    /// The fully resolved expression, with aliases already substituted.
+    pub expr: String,
+    /// The rendered result of every part of the expression, in order.
+    pub results: Vec<String>,
+    /// The name of the [`GameSystem`](crate::dice::GameSystem) that interpreted the roll.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system: Option<String>,
+}
+
+/// A commit-reveal ledger of server-side seeds and the rolls made with them.
+///
+/// Every roll gets a [`RollId`] and a random number generator derived from the secret seed of
+/// the current epoch. Only the SHA-256 hash of that seed (the commitment) is published while the
+/// epoch is active. Once the seed is revealed the epoch is closed, and anyone can recompute every
+/// roll of that epoch: the generator is a `ChaCha20` RNG seeded with
+/// `sha256("<secret>:<epoch>:<nonce>")`.
+///
+/// Nonces are reserved in blocks with [`reserve`](Ledger::reserve). A ledger loaded from JSON
+/// continues after the last reserved block, so ids issued after it was last saved are never
+/// issued again.
+///
+/// # Examples
+///
+/// ```
+/// use rand::Rng;
+/// use walzecore::dice::{Ledger, RollRecord};
+///
+/// let mut ledger = Ledger::new("{}")?;
+/// let (id, mut rng) = ledger.issue();
+/// let roll: u32 = rng.gen_range(1..=20);
+/// ledger.record(id, RollRecord { expr: "1d20".into(), results: vec![roll.to_string()], system: None });
+///
+/// // the seed has to be revealed before the roll can be verified
+/// assert!(ledger.verify(&id.to_string()).is_err());
+/// ledger.reveal();
+///
+/// let (record, mut rng) = ledger.verify(&id.to_string())?;
+/// assert_eq!(record.results[0], rng.gen_range(1..=20u32).to_string());
+/// # Ok::<(), self::walzecore::dice::Error>(())
+/// ```
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Ledger {
+    #[serde(default)]
+    nonce: u32,
+    #[serde(default)]
+    reserved: u32,
+    #[serde(default)]
+    seeds: Vec<String>,
+    #[serde(default)]
+    rolls: BTreeMap<RollId, RollRecord>,
+}
+
+impl Default for Ledger {
+    fn default() -> Self {
+        Self {
+            nonce: 0,
+            reserved: 0,
+            seeds: vec![new_secret()],
+            rolls: BTreeMap::new(),
+        }
+    }
+}
+
+impl Ledger {
+    /// Creates a new `Ledger` from a JSON string.
+    ///
+    /// A ledger without seeds, like `{}`, starts with a new secret seed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON cannot be deserialized, so a corrupt ledger is never
+    /// replaced by a fresh one whose secret no longer matches the published commitments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::dice::Ledger;
+    ///
+    /// assert_eq!(Ledger::new("{}")?.epoch(), 0);
+    /// assert!(Ledger::new("{\"seeds\": 3}").is_err());
+    /// # Ok::<(), self::walzecore::dice::Error>(())
+    /// ```
+    pub fn new(json: &str) -> Result<Ledger> {
+        let mut ledger: Ledger = serde_json::from_str(json)?;
+        if ledger.seeds.is_empty() {
+            return Ok(Ledger::default());
+        }
+        // every nonce of the reserved blocks may have been issued before the ledger was saved
+        ledger.nonce = ledger.nonce.max(ledger.reserved);
+        Ok(ledger)
+    }
+
+    /// Converts the ledger to a JSON string.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// Returns the current seed epoch.
+    pub fn epoch(&self) -> u32 {
+        u32::try_from(self.seeds.len() - 1).unwrap_or(u32::MAX)
+    }
+
+    /// Returns the hex encoded SHA-256 hash of the current, unrevealed seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::dice::Ledger;
+    ///
+    /// let mut ledger = Ledger::new("{}")?;
+    /// let commitment = ledger.commitment();
+    /// let (_, secret) = ledger.reveal();
+    /// assert_eq!(walzecore::dice::seed::commitment_of(&secret), commitment);
+    /// # Ok::<(), self::walzecore::dice::Error>(())
+    /// ```
+    pub fn commitment(&self) -> String {
+        commitment_of(self.current_secret())
+    }
+
+    /// Reserves a new block of nonces if the current one is used up.
+    ///
+    /// Returns `true` if a block was reserved, in which case the ledger has to be saved before
+    /// the next [`issue`](Ledger::issue) so the ids of the block cannot be issued again after a
+    /// restart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::dice::Ledger;
+    ///
+    /// let mut ledger = Ledger::new("{}")?;
+    /// assert!(ledger.reserve());
+    /// assert!(!ledger.reserve());
+    /// # Ok::<(), self::walzecore::dice::Error>(())
+    /// ```
+    pub fn reserve(&mut self) -> bool {
+        if self.nonce < self.reserved {
+            return false;
+        }
+        self.reserved = self.nonce.saturating_add(NONCE_BLOCK);
+        true
+    }
+
+    /// Issues a new [`RollId`] in the current epoch and returns the generator to roll with.
+    pub fn issue(&mut self) -> (RollId, ChaCha20Rng) {
+        self.reserve();
+        let id = RollId {
+            epoch: self.epoch(),
+            nonce: self.nonce,
+        };
+        self.nonce = self.nonce.wrapping_add(1);
+        (id, derive_rng(self.current_secret(), id))
+    }
+
+    /// Stores what was rolled under `id`, dropping the oldest record if the ledger is full.
+    pub fn record(&mut self, id: RollId, record: RollRecord) {
+        self.rolls.insert(id, record);
+        while self.rolls.len() > MAX_RECORDS {
+            self.rolls.pop_first();
+        }
+    }
+
+    /// Closes the current epoch and starts a new one with a fresh secret.
+    ///
+    /// Returns the closed epoch and its now public secret.
+    pub fn reveal(&mut self) -> (u32, String) {
+        let epoch = self.epoch();
+        let secret = self.current_secret().to_string();
+        self.seeds.push(new_secret());
+        self.nonce = 0;
+        self.reserved = 0;
+        (epoch, secret)
+    }
+
+    /// Returns the revealed secret of a closed epoch.
+    pub fn secret(&self, epoch: u32) -> Option<&str> {
+        if epoch >= self.epoch() {
+            return None;
+        }
+        self.seeds.get(epoch as usize).map(String::as_str)
+    }
+
+    /// Looks up a roll and re-derives the generator it was rolled with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the id is malformed, the roll is unknown, or the seed of its epoch
+    /// has not been revealed yet.
+    pub fn verify(&self, id: &str) -> Result<(&RollRecord, ChaCha20Rng)> {
+        let roll_id: RollId = id.parse()?;
+        let record = self
+            .rolls
+            .get(&roll_id)
+            .ok_or_else(|| dice::Error::RollNotFound(id.to_string()))?;
+        let Some(secret) = self.secret(roll_id.epoch) else {
+            return Err(dice::Error::SeedNotRevealed(
+                id.to_string(),
+                self.commitment(),
+            ));
+        };
+
+        Ok((record, derive_rng(secret, roll_id)))
+    }
+
+    fn current_secret(&self) -> &str {
+        self.seeds.last().map_or("", String::as_str)
+    }
+}
+
+/// Returns the hex encoded SHA-256 hash of a secret seed.
+pub fn commitment_of(secret: &str) -> String {
+    to_hex(&Sha256::digest(secret.as_bytes()))
+}
+
+fn derive_rng(secret: &str, id: RollId) -> ChaCha20Rng {
+    let digest = Sha256::digest(format!("{secret}:{}:{}", id.epoch, id.nonce).as_bytes());
+    ChaCha20Rng::from_seed(digest.into())
+}
+
+fn new_secret() -> String {
+    to_hex(&rand::thread_rng().gen::<[u8; 32]>())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reloading_never_issues_an_id_twice() {
+        let mut ledger = Ledger::new("{}").unwrap();
+        assert!(ledger.reserve());
+        let saved = ledger.to_json();
+
+        let mut issued: Vec<RollId> = (0..3).map(|_| ledger.issue().0).collect();
+        // the ledger is lost without being saved again, e.g. in a crash
+        let mut ledger = Ledger::new(&saved).unwrap();
+        for _ in 0..NONCE_BLOCK * 2 {
+            if ledger.reserve() {
+                ledger = Ledger::new(&ledger.to_json()).unwrap();
+            }
+            issued.push(ledger.issue().0);
+        }
+
+        let mut unique = issued.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), issued.len());
+    }
+}
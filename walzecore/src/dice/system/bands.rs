@@ -0,0 +1,124 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::dice;
+use crate::dice::Result;
+
+/// The outcome tier games like Blades in the Dark and PbtA read their dice as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Tier {
+    Failure,
+    Partial,
+    Success,
+    Critical,
+}
+
+impl Tier {
+    /// Every tier, from worst to best.
+    pub const ALL: [Tier; 4] = [Tier::Failure, Tier::Partial, Tier::Success, Tier::Critical];
+
+    /// Returns the short name the tier is configured with.
+    pub fn name(self) -> &'static str {
+        match self {
+            Tier::Failure => "failure",
+            Tier::Partial => "partial",
+            Tier::Success => "success",
+            Tier::Critical => "critical",
+        }
+    }
+
+    /// Returns what the tier means when a namespace does not describe it.
+    pub fn default_description(self) -> &'static str {
+        match self {
+            Tier::Failure => "things go badly",
+            Tier::Partial => "you do it, but at a cost",
+            Tier::Success => "you do it",
+            Tier::Critical => "you do it with increased effect",
+        }
+    }
+
+    /// Reads the highest die of a Blades in the Dark pool, or the lowest of the two dice rolled
+    /// for a pool of zero dice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::dice::system::bands::Tier;
+    ///
+    /// assert_eq!(Tier::of_blades(&[3, 5, 2], false), Tier::Partial);
+    /// assert_eq!(Tier::of_blades(&[6, 1], false), Tier::Success);
+    /// assert_eq!(Tier::of_blades(&[6, 6, 1], false), Tier::Critical);
+    /// assert_eq!(Tier::of_blades(&[6, 6], true), Tier::Success);
+    /// assert_eq!(Tier::of_blades(&[6, 2], true), Tier::Failure);
+    /// ```
+    pub fn of_blades(faces: &[u64], zero_dice: bool) -> Tier {
+        let sixes = faces.iter().filter(|&&face| face == 6).count();
+        let read = if zero_dice {
+            faces.iter().min()
+        } else {
+            faces.iter().max()
+        };
+        match read.copied().unwrap_or(0) {
+            6 if sixes >= 2 && !zero_dice => Tier::Critical,
+            6 => Tier::Success,
+            4 | 5 => Tier::Partial,
+            _ => Tier::Failure,
+        }
+    }
+
+    /// Reads the total of a Powered by the Apocalypse move, 2d6 plus a stat.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::dice::system::bands::Tier;
+    ///
+    /// assert_eq!(Tier::of_pbta(6), Tier::Failure);
+    /// assert_eq!(Tier::of_pbta(9), Tier::Partial);
+    /// assert_eq!(Tier::of_pbta(10), Tier::Success);
+    /// ```
+    pub fn of_pbta(total: i64) -> Tier {
+        match total {
+            ..=6 => Tier::Failure,
+            7..=9 => Tier::Partial,
+            _ => Tier::Success,
+        }
+    }
+}
+
+impl fmt::Display for Tier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Tier::Failure => "failure",
+            Tier::Partial => "partial success",
+            Tier::Success => "full success",
+            Tier::Critical => "critical success",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for Tier {
+    type Err = dice::Error;
+
+    /// Parses a tier from its short name, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::dice::system::bands::Tier;
+    ///
+    /// assert_eq!("Partial".parse::<Tier>()?, Tier::Partial);
+    /// assert!("mixed".parse::<Tier>().is_err());
+    /// # Ok::<(), self::walzecore::dice::Error>(())
+    /// ```
+    fn from_str(s: &str) -> Result<Self> {
+        Tier::ALL
+            .into_iter()
+            .find(|tier| tier.name().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| dice::Error::UnknownTier(s.trim().to_string()))
+    }
+}
@@ -0,0 +1,161 @@
+use lazy_static::lazy_static;
+use rand::Rng;
+use regex::Regex;
+
+use crate::dice::system::bands::Tier;
+use crate::dice::system::{Dice, GameSystem};
+use crate::dice::DiceRoll;
+
+lazy_static! {
+    /// Matches `blades(<dice>)`
+    static ref ACTION_REGEX: Regex = Regex::new(r"^\s*blades\(\s*([0-9]+)\s*\)\s*$").unwrap();
+    /// Matches a plain pool of d6, e.g. `3d6`
+    static ref POOL_REGEX: Regex = Regex::new(r"^\s*[1-9][0-9]*d6\s*$").unwrap();
+}
+
+/// A Blades in the Dark action roll, `blades(3)`, of a pool of d6.
+///
+/// A pool of zero dice rolls two dice and reads the lowest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Action {
+    pub dice: u64,
+}
+
+impl Action {
+    /// Parses an action roll, returning `None` if the expression is not one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::dice::system::blades::Action;
+    ///
+    /// assert_eq!(Action::parse("blades(0)"), Some(Action { dice: 0 }));
+    /// assert_eq!(Action::parse("3d6"), None);
+    /// ```
+    pub fn parse(expr: &str) -> Option<Action> {
+        let caps = ACTION_REGEX.captures(expr)?;
+        let dice = caps[1].parse().unwrap_or(u64::MAX);
+        Some(Action { dice })
+    }
+
+    /// Returns the number of dice actually rolled.
+    pub fn rolled_dice(&self) -> u64 {
+        if self.dice == 0 {
+            2
+        } else {
+            self.dice
+        }
+    }
+
+    /// Rolls the pool.
+    pub fn roll<R: Rng>(&self, rng: &mut R) -> ActionRoll {
+        let faces = (0..self.rolled_dice())
+            .map(|_| rng.gen_range(1..=6))
+            .collect();
+        ActionRoll {
+            faces,
+            zero_dice: self.dice == 0,
+        }
+    }
+}
+
+/// The faces of a rolled [`Action`].
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::system::{bands::Tier, blades::ActionRoll};
+///
+/// let roll = ActionRoll { faces: vec![6, 2, 6], zero_dice: false };
+/// assert_eq!(roll.tier(), Tier::Critical);
+/// assert_eq!(roll.render(), "[6, 2, 6] highest 6\n**critical success**");
+///
+/// let roll = ActionRoll { faces: vec![5, 6], zero_dice: true };
+/// assert_eq!(roll.render(), "[5, 6] lowest 5\n**partial success**");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActionRoll {
+    pub faces: Vec<u64>,
+    pub zero_dice: bool,
+}
+
+impl ActionRoll {
+    /// Returns the outcome tier of the roll.
+    pub fn tier(&self) -> Tier {
+        Tier::of_blades(&self.faces, self.zero_dice)
+    }
+
+    /// Returns the die the roll is read by.
+    pub fn read(&self) -> u64 {
+        let faces = self.faces.iter().copied();
+        if self.zero_dice {
+            faces.min().unwrap_or(0)
+        } else {
+            faces.max().unwrap_or(0)
+        }
+    }
+
+    /// Renders the dice, the die read and the outcome tier.
+    pub fn render(&self) -> String {
+        let read = if self.zero_dice { "lowest" } else { "highest" };
+        format!(
+            "{:?} {read} {}\n**{}**",
+            self.faces,
+            self.read(),
+            self.tier()
+        )
+    }
+}
+
+/// Forged in the Dark games, reading the highest d6 of a pool as an outcome tier.
+///
+/// Only plain pools of d6 like `3d6` are read, other rolls such as damage are left as they are.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::system::{bands::Tier, Blades, Dice};
+/// use walzecore::dice::{DiceRoll, GameSystem};
+///
+/// let mut roll = DiceRoll::new("3d6", "[2, 4, 1] = 7".into(), Some(7));
+/// Blades.interpret(&mut roll, &[Dice { sides: 6, faces: vec![2, 4, 1] }]);
+/// assert_eq!(roll.tier, Some(Tier::Partial));
+/// assert_eq!(roll.result, "[2, 4, 1] = 7\n**partial success**");
+///
+/// let mut roll = DiceRoll::new("1d6+2", "[6] + 2 = 8".into(), Some(8));
+/// Blades.interpret(&mut roll, &[Dice { sides: 6, faces: vec![6] }]);
+/// assert_eq!(roll.tier, None);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Blades;
+
+impl GameSystem for Blades {
+    fn name(&self) -> &'static str {
+        "blades"
+    }
+
+    fn description(&self) -> &'static str {
+        "Blades in the Dark, the highest d6 read as failure, partial, success or critical, use blades(dice)"
+    }
+
+    fn interpret(&self, roll: &mut DiceRoll, dice: &[Dice]) {
+        if !POOL_REGEX.is_match(&roll.expr) {
+            return;
+        }
+        let faces: Vec<u64> = dice
+            .iter()
+            .filter(|dice| dice.sides == 6)
+            .flat_map(|dice| dice.faces.iter().copied())
+            .collect();
+        if faces.is_empty() {
+            return;
+        }
+        let tier = Tier::of_blades(&faces, false);
+        roll.result.push_str(&format!("\n**{tier}**"));
+        roll.tier = Some(tier);
+    }
+
+    fn is_own_syntax(&self, part: &str) -> bool {
+        Action::parse(part).is_some()
+    }
+}
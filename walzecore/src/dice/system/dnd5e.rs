@@ -0,0 +1,65 @@
+use crate::dice::expr::{leading_die, natural_face};
+use crate::dice::system::{flag_natural, Dice, GameSystem};
+use crate::dice::target::Natural;
+use crate::dice::DiceRoll;
+
+/// Dungeons & Dragons 5th edition.
+///
+/// A natural 20 or 1 on the d20 driving the roll is flagged, reading the kept die when it is
+/// rolled with advantage (`2d20K1`) or disadvantage (`2d20k1`). Against a target, a natural 20
+/// always succeeds and a natural 1 always fails, whatever the total.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::system::{Dice, Dnd5e};
+/// use walzecore::dice::{target::{Natural, Target}, DiceRoll, GameSystem};
+///
+/// let mut roll = DiceRoll::new("2d20k1+5", "[20, 1] + 5 = 6".into(), Some(6));
+/// Dnd5e.interpret(&mut roll, &[Dice { sides: 20, faces: vec![20, 1] }]);
+/// assert_eq!(roll.natural, Some(Natural::Min));
+///
+/// let mut roll = DiceRoll::new("d20 + 2", "[20] + 2 = 22".into(), Some(22));
+/// Dnd5e.interpret(&mut roll, &[Dice { sides: 20, faces: vec![20] }]);
+/// assert_eq!(roll.natural, Some(Natural::Max));
+///
+/// let (_, target) = Target::split("1d20+20 vs 15");
+/// let mut roll = DiceRoll::new("1d20+20 vs 15", "[1] + 20 = 21".into(), Some(21));
+/// roll.verdict = target.map(|target| target.check(21, None));
+/// Dnd5e.interpret(&mut roll, &[Dice { sides: 20, faces: vec![1] }]);
+/// assert!(roll.verdict.is_some_and(|verdict| !verdict.success));
+///
+/// let mut roll = DiceRoll::new("1d200", "[20] = 20".into(), Some(20));
+/// Dnd5e.interpret(&mut roll, &[Dice { sides: 200, faces: vec![20] }]);
+/// assert_eq!(roll.natural, None);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Dnd5e;
+
+impl GameSystem for Dnd5e {
+    fn name(&self) -> &'static str {
+        "5e"
+    }
+
+    fn description(&self) -> &'static str {
+        "D&D 5e, a natural 20 or 1 on the kept d20 always hits or misses, also with advantage"
+    }
+
+    fn interpret(&self, roll: &mut DiceRoll, dice: &[Dice]) {
+        if leading_die(&roll.expr) != Some(20) {
+            return;
+        }
+        let faces = dice.first().map_or(&[][..], |dice| dice.faces.as_slice());
+        let Some(face) = natural_face(&roll.expr, faces) else {
+            return;
+        };
+        flag_natural(roll, face, 20);
+        if let Some(verdict) = &mut roll.verdict {
+            match verdict.natural {
+                Some(Natural::Max) => verdict.success = true,
+                Some(Natural::Min) => verdict.success = false,
+                None => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::dice::expr::{leading_die, natural_face};
+use crate::dice::system::{flag_natural, Dice, GameSystem};
+use crate::dice::DiceRoll;
+
+/// The die whose natural results are flagged as critical or fumbled.
+const CRIT_DIE: u64 = 20;
+
+/// Plain totals, flagging a natural 20 or 1 when a single d20 leads the roll, or the kept die
+/// of a pool keeping one d20, `2d20K1`.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::system::{Dice, Generic};
+/// use walzecore::dice::{target::Natural, DiceRoll, GameSystem};
+///
+/// let mut roll = DiceRoll::new("1d20+5", "[1] + 5 = 6".into(), Some(6));
+/// Generic.interpret(&mut roll, &[Dice { sides: 20, faces: vec![1] }]);
+/// assert_eq!(roll.natural, Some(Natural::Min));
+///
+/// let mut roll = DiceRoll::new("2d20+5", "[20, 20] + 5 = 45".into(), Some(45));
+/// Generic.interpret(&mut roll, &[Dice { sides: 20, faces: vec![20, 20] }]);
+/// assert_eq!(roll.natural, None);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Generic;
+
+impl GameSystem for Generic {
+    fn name(&self) -> &'static str {
+        "generic"
+    }
+
+    fn description(&self) -> &'static str {
+        "plain totals, natural 20 and 1 on a single or kept d20 are flagged"
+    }
+
+    fn interpret(&self, roll: &mut DiceRoll, dice: &[Dice]) {
+        if leading_die(&roll.expr) != Some(CRIT_DIE) {
+            return;
+        }
+        let faces = dice.first().map_or(&[][..], |dice| dice.faces.as_slice());
+        if let Some(face) = natural_face(&roll.expr, faces) {
+            flag_natural(roll, face, CRIT_DIE);
+        }
+    }
+}
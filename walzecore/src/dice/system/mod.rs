@@ -0,0 +1,129 @@
+pub mod bands;
+pub mod blades;
+pub mod dnd5e;
+pub mod generic;
+pub mod pbta;
+pub mod percentile;
+pub mod wrath;
+
+use std::fmt::Debug;
+
+use crate::dice;
+use crate::dice::target::Natural;
+use crate::dice::{DiceRoll, Result};
+
+pub use blades::Blades;
+pub use dnd5e::Dnd5e;
+pub use generic::Generic;
+pub use pbta::Pbta;
+pub use percentile::Coc;
+pub use wrath::Wrath;
+
+/// Every game system a namespace can be bound to.
+static SYSTEMS: &[&dyn GameSystem] = &[&Generic, &Dnd5e, &Wrath, &Blades, &Pbta, &Coc];
+
+/// The faces rolled for one dice term of an expression, e.g. the `[4, 6]` of `2d6`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dice {
+    pub sides: u64,
+    pub faces: Vec<u64>,
+}
+
+/// The dice semantics of a game.
+///
+/// A system interprets a rolled part of an expression the way the game reads it, e.g. by
+/// flagging critical results, counting successes or naming the outcome. Parts written in the
+/// system's own syntax, like `blades(3)`, are only rolled in namespaces bound to it.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::system::{self, Dice};
+/// use walzecore::dice::{DiceRoll, GameSystem};
+///
+/// let dnd = system::by_name("5e")?;
+/// let mut roll = DiceRoll::new("2d20K1+5", "[20, 3] + 5 = 25".into(), Some(25));
+/// dnd.interpret(&mut roll, &[Dice { sides: 20, faces: vec![20, 3] }]);
+/// assert!(roll.is_critical());
+/// # Ok::<(), self::walzecore::dice::Error>(())
+/// ```
+pub trait GameSystem: Debug + Send + Sync {
+    /// The short name a namespace is bound to the system with.
+    fn name(&self) -> &'static str;
+
+    /// A one line description of the system.
+    fn description(&self) -> &'static str;
+
+    /// Interprets a rolled part of an expression from the faces of its dice terms, in the
+    /// order they appear in the expression.
+    fn interpret(&self, roll: &mut DiceRoll, dice: &[Dice]);
+
+    /// Returns `true` if the part is written in the system's own syntax, e.g. `blades(3)`.
+    fn is_own_syntax(&self, _part: &str) -> bool {
+        false
+    }
+}
+
+/// Returns every available game system.
+pub fn systems() -> &'static [&'static dyn GameSystem] {
+    SYSTEMS
+}
+
+/// Returns the system used for namespaces that are not bound to one.
+pub fn default_system() -> &'static dyn GameSystem {
+    &Generic
+}
+
+/// Looks up a game system by its name, ignoring case.
+///
+/// # Errors
+///
+/// Returns an error if there is no system with that name.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::system;
+///
+/// assert_eq!(system::by_name("5E")?.name(), "5e");
+/// assert!(system::by_name("gurps").is_err());
+/// # Ok::<(), self::walzecore::dice::Error>(())
+/// ```
+pub fn by_name(name: &str) -> Result<&'static dyn GameSystem> {
+    let name = name.trim();
+    SYSTEMS
+        .iter()
+        .copied()
+        .find(|system| system.name().eq_ignore_ascii_case(name))
+        .ok_or_else(|| dice::Error::UnknownSystem(name.to_string()))
+}
+
+/// Returns the system whose own syntax the part is written in, if it is another system than
+/// the one the part is rolled with.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::system::{self, Blades, Generic};
+///
+/// assert_eq!(system::foreign_syntax(&Generic, "blades(3)").map(|s| s.name()), Some("blades"));
+/// assert!(system::foreign_syntax(&Blades, "blades(3)").is_none());
+/// assert!(system::foreign_syntax(&Generic, "3d6").is_none());
+/// ```
+pub fn foreign_syntax(system: &dyn GameSystem, part: &str) -> Option<&'static dyn GameSystem> {
+    SYSTEMS
+        .iter()
+        .copied()
+        .filter(|other| other.name() != system.name())
+        .find(|other| other.is_own_syntax(part))
+}
+
+/// Flags a natural maximum or minimum of the die driving the roll on the roll and its verdict.
+pub fn flag_natural(roll: &mut DiceRoll, face: u64, sides: u64) {
+    let natural = Natural::of(face, sides);
+    roll.natural = natural;
+    if let Some(verdict) = &mut roll.verdict {
+        verdict.natural = natural;
+        verdict.face = natural.map(|_| face);
+    }
+}
@@ -0,0 +1,144 @@
+use lazy_static::lazy_static;
+use rand::Rng;
+use regex::Regex;
+
+use crate::dice::system::bands::Tier;
+use crate::dice::system::{Dice, GameSystem};
+use crate::dice::DiceRoll;
+
+lazy_static! {
+    /// Matches `pbta(<stat>)`, e.g. `pbta(+2)` or `pbta(-1)`
+    static ref MOVE_REGEX: Regex =
+        Regex::new(r"^\s*pbta\(\s*(?:([+-]?)\s*([0-9]+))?\s*\)\s*$").unwrap();
+    /// Matches 2d6 plus or minus a stat, e.g. `2d6+1`
+    static ref STAT_REGEX: Regex = Regex::new(r"^\s*2d6\s*(?:[+-]\s*[0-9]+\s*)?$").unwrap();
+}
+
+/// A Powered by the Apocalypse move, `pbta(+2)`, rolling 2d6 plus a stat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub stat: i64,
+}
+
+impl Move {
+    /// Parses a move, returning `None` if the expression is not one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::dice::system::pbta::Move;
+    ///
+    /// assert_eq!(Move::parse("pbta(+2)"), Some(Move { stat: 2 }));
+    /// assert_eq!(Move::parse("pbta(-1)"), Some(Move { stat: -1 }));
+    /// assert_eq!(Move::parse("pbta()"), Some(Move { stat: 0 }));
+    /// assert_eq!(Move::parse("2d6+2"), None);
+    /// ```
+    pub fn parse(expr: &str) -> Option<Move> {
+        let caps = MOVE_REGEX.captures(expr)?;
+        let stat = caps.get(2).map_or(0, |m| m.as_str().parse().unwrap_or(0));
+        match caps.get(1).map(|m| m.as_str()) {
+            Some("-") => Some(Move { stat: -stat }),
+            _ => Some(Move { stat }),
+        }
+    }
+
+    /// Rolls the move.
+    pub fn roll<R: Rng>(&self, rng: &mut R) -> MoveRoll {
+        MoveRoll {
+            faces: [rng.gen_range(1..=6), rng.gen_range(1..=6)],
+            stat: self.stat,
+        }
+    }
+}
+
+/// The dice of a rolled [`Move`].
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::system::{bands::Tier, pbta::MoveRoll};
+///
+/// let roll = MoveRoll { faces: [3, 5], stat: 1 };
+/// assert_eq!((roll.total(), roll.tier()), (9, Tier::Partial));
+/// assert_eq!(roll.render(), "[3, 5] + 1 = 9\n**partial success**");
+///
+/// let roll = MoveRoll { faces: [6, 5], stat: -1 };
+/// assert_eq!(roll.render(), "[6, 5] - 1 = 10\n**full success**");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MoveRoll {
+    pub faces: [u64; 2],
+    pub stat: i64,
+}
+
+impl MoveRoll {
+    /// Returns the sum of the dice and the stat.
+    pub fn total(&self) -> i64 {
+        let dice: u64 = self.faces.iter().sum();
+        i64::try_from(dice).unwrap_or(i64::MAX) + self.stat
+    }
+
+    /// Returns the outcome tier of the roll.
+    pub fn tier(&self) -> Tier {
+        Tier::of_pbta(self.total())
+    }
+
+    /// Renders the dice, the total and the outcome tier.
+    pub fn render(&self) -> String {
+        let sign = if self.stat < 0 { '-' } else { '+' };
+        format!(
+            "{:?} {sign} {} = {}\n**{}**",
+            self.faces,
+            self.stat.abs(),
+            self.total(),
+            self.tier()
+        )
+    }
+}
+
+/// Powered by the Apocalypse games, reading the total as 6- / 7-9 / 10+.
+///
+/// Only 2d6 plus or minus a stat is read, other rolls such as damage are left as they are.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::system::{bands::Tier, Pbta};
+/// use walzecore::dice::{DiceRoll, GameSystem};
+///
+/// let mut roll = DiceRoll::new("2d6+1", "[2, 3] + 1 = 6".into(), Some(6));
+/// Pbta.interpret(&mut roll, &[]);
+/// assert_eq!(roll.tier, Some(Tier::Failure));
+///
+/// let mut roll = DiceRoll::new("1d10+3", "[8] + 3 = 11".into(), Some(11));
+/// Pbta.interpret(&mut roll, &[]);
+/// assert_eq!(roll.tier, None);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Pbta;
+
+impl GameSystem for Pbta {
+    fn name(&self) -> &'static str {
+        "pbta"
+    }
+
+    fn description(&self) -> &'static str {
+        "Powered by the Apocalypse, 2d6 + stat read as 6- / 7-9 / 10+, use pbta(+stat)"
+    }
+
+    fn interpret(&self, roll: &mut DiceRoll, _dice: &[Dice]) {
+        if !STAT_REGEX.is_match(&roll.expr) {
+            return;
+        }
+        let Some(total) = roll.total else {
+            return;
+        };
+        let tier = Tier::of_pbta(total);
+        roll.result.push_str(&format!("\n**{tier}**"));
+        roll.tier = Some(tier);
+    }
+
+    fn is_own_syntax(&self, part: &str) -> bool {
+        Move::parse(part).is_some()
+    }
+}
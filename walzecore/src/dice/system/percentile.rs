@@ -0,0 +1,297 @@
+use std::fmt;
+
+use lazy_static::lazy_static;
+use rand::Rng;
+use regex::Regex;
+
+use crate::dice;
+use crate::dice::expr::leading_die;
+use crate::dice::system::{Dice, GameSystem};
+use crate::dice::target::{Natural, Target, Verdict};
+use crate::dice::{DiceRoll, Result};
+
+/// Maximum number of bonus or penalty dice on a single check.
+pub const MAX_MODIFIER: u64 = 2;
+
+lazy_static! {
+    /// Matches `coc(<skill>[, <bonus or penalty dice>])`
+    static ref CHECK_REGEX: Regex =
+        Regex::new(r"^\s*coc\(\s*([^,()]+?)\s*(?:,\s*([+-]?)\s*([0-9]+)\s*)?\)\s*$").unwrap();
+}
+
+/// How well a percentile check went, from worst to best.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Fumble,
+    Failure,
+    Regular,
+    Hard,
+    Extreme,
+    Critical,
+}
+
+impl Level {
+    /// Reads a percentile roll against a skill value.
+    ///
+    /// A 01 is a critical success. A 100 is a fumble, as is anything from 96 up when the skill
+    /// is below 50.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::dice::system::percentile::Level;
+    ///
+    /// assert_eq!(Level::of(1, 20), Level::Critical);
+    /// assert_eq!(Level::of(12, 60), Level::Extreme);
+    /// assert_eq!(Level::of(30, 60), Level::Hard);
+    /// assert_eq!(Level::of(60, 60), Level::Regular);
+    /// assert_eq!(Level::of(61, 60), Level::Failure);
+    /// assert_eq!(Level::of(97, 45), Level::Fumble);
+    /// assert_eq!(Level::of(97, 50), Level::Failure);
+    /// assert_eq!(Level::of(100, 99), Level::Fumble);
+    /// ```
+    pub fn of(roll: u64, skill: u64) -> Level {
+        match roll {
+            1 => Level::Critical,
+            100 => Level::Fumble,
+            r if r >= 96 && skill < 50 => Level::Fumble,
+            r if r <= skill / 5 => Level::Extreme,
+            r if r <= skill / 2 => Level::Hard,
+            r if r <= skill => Level::Regular,
+            _ => Level::Failure,
+        }
+    }
+
+    /// Returns `true` for every level of success.
+    pub fn is_success(self) -> bool {
+        self >= Level::Regular
+    }
+
+    /// Returns the name of the level.
+    pub fn name(self) -> &'static str {
+        match self {
+            Level::Fumble => "fumble",
+            Level::Failure => "failure",
+            Level::Regular => "regular success",
+            Level::Hard => "hard success",
+            Level::Extreme => "extreme success",
+            Level::Critical => "critical success",
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A Call of Cthulhu skill check, `coc(60)`, rolling d100 against a skill value.
+///
+/// Bonus dice, `coc(60, +1)`, or penalty dice, `coc(60, -1)`, roll extra tens dice and keep the
+/// lowest or highest result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Check {
+    pub skill: u64,
+    /// Bonus dice when positive, penalty dice when negative.
+    pub modifier: i64,
+}
+
+impl Check {
+    /// Parses a skill check.
+    ///
+    /// Returns `Ok(None)` if the expression is not a skill check.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the skill value is not a number, e.g. because its alias is not
+    /// defined, or if there are more than [`MAX_MODIFIER`] bonus or penalty dice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::dice::system::percentile::Check;
+    ///
+    /// assert_eq!(Check::parse("coc(60)")?, Some(Check { skill: 60, modifier: 0 }));
+    /// assert_eq!(Check::parse("coc(45, -2)")?, Some(Check { skill: 45, modifier: -2 }));
+    /// assert_eq!(Check::parse("1d100")?, None);
+    /// assert!(Check::parse("coc($spot_hidden)").is_err());
+    /// assert!(Check::parse("coc(60, +3)").is_err());
+    /// # Ok::<(), self::walzecore::dice::Error>(())
+    /// ```
+    pub fn parse(expr: &str) -> Result<Option<Check>> {
+        let Some(caps) = CHECK_REGEX.captures(expr) else {
+            return Ok(None);
+        };
+        let skill = caps[1]
+            .parse()
+            .map_err(|_| dice::Error::InvalidSkill(caps[1].to_string()))?;
+
+        let dice: u64 = caps
+            .get(3)
+            .map_or(0, |m| m.as_str().parse().unwrap_or(u64::MAX));
+        if dice > MAX_MODIFIER {
+            return Err(dice::Error::Simple(
+                "a check can have at most 2 bonus or penalty dice",
+            ));
+        }
+        let dice = i64::try_from(dice).unwrap_or(0);
+        let modifier = match caps.get(2).map(|m| m.as_str()) {
+            Some("-") => -dice,
+            _ => dice,
+        };
+        Ok(Some(Check { skill, modifier }))
+    }
+
+    /// Returns the number of tens dice rolled.
+    pub fn tens_dice(&self) -> u64 {
+        1 + self.modifier.unsigned_abs()
+    }
+
+    /// Rolls the check.
+    pub fn roll<R: Rng>(&self, rng: &mut R) -> CheckRoll {
+        let units = rng.gen_range(0..=9);
+        let tens = (0..self.tens_dice())
+            .map(|_| rng.gen_range(0..=9) * 10)
+            .collect();
+        CheckRoll {
+            check: *self,
+            units,
+            tens,
+        }
+    }
+}
+
+/// The dice of a rolled [`Check`].
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::system::percentile::{Check, CheckRoll, Level};
+///
+/// let check = Check { skill: 60, modifier: 1 };
+/// let roll = CheckRoll { check, units: 4, tens: vec![70, 20] };
+/// assert_eq!((roll.value(), roll.level()), (24, Level::Hard));
+/// assert_eq!(
+///     roll.render(),
+///     "tens: [70, 20] units: 4 (1 bonus die)\n24 vs 60 (hard 30, extreme 12)"
+/// );
+/// assert_eq!(roll.verdict().to_string(), "hard success by 36");
+///
+/// // 00 and 0 make 100
+/// let check = Check { skill: 60, modifier: -1 };
+/// let roll = CheckRoll { check, units: 0, tens: vec![0, 50] };
+/// assert_eq!((roll.value(), roll.level()), (100, Level::Fumble));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckRoll {
+    pub check: Check,
+    pub units: u64,
+    pub tens: Vec<u64>,
+}
+
+impl CheckRoll {
+    /// Returns the rolled value from 1 to 100, keeping the best tens die with bonus dice and
+    /// the worst with penalty dice.
+    pub fn value(&self) -> u64 {
+        let values = self.tens.iter().map(|&tens| match tens + self.units {
+            0 => 100,
+            value => value,
+        });
+        let value = if self.check.modifier > 0 {
+            values.min()
+        } else {
+            values.max()
+        };
+        value.unwrap_or(100)
+    }
+
+    /// Returns the success level of the roll.
+    pub fn level(&self) -> Level {
+        Level::of(self.value(), self.check.skill)
+    }
+
+    /// Renders the dice and the value against the skill, the success level is named by the
+    /// [`verdict`](CheckRoll::verdict).
+    pub fn render(&self) -> String {
+        let skill = self.check.skill;
+        let modifier = match self.check.modifier {
+            0 => String::new(),
+            1 => " (1 bonus die)".to_string(),
+            -1 => " (1 penalty die)".to_string(),
+            n if n > 0 => format!(" ({n} bonus dice)"),
+            n => format!(" ({} penalty dice)", n.unsigned_abs()),
+        };
+        format!(
+            "tens: {:?} units: {}{modifier}\n{} vs {skill} (hard {}, extreme {})",
+            self.tens,
+            self.units,
+            self.value(),
+            skill / 2,
+            skill / 5
+        )
+    }
+
+    /// Returns the outcome against the skill value, flagging criticals and fumbles.
+    pub fn verdict(&self) -> Verdict {
+        let value = self.value();
+        verdict(value, self.check.skill)
+    }
+}
+
+fn verdict(value: u64, skill: u64) -> Verdict {
+    let level = Level::of(value, skill);
+    let natural = match level {
+        Level::Critical => Some(Natural::Max),
+        Level::Fumble => Some(Natural::Min),
+        _ => None,
+    };
+    Verdict {
+        success: level.is_success(),
+        margin: i64::try_from(skill.abs_diff(value)).unwrap_or(i64::MAX),
+        natural,
+        face: natural.map(|_| value),
+        outcome: Some(level.name()),
+    }
+}
+
+/// Call of Cthulhu, reading a d100 roll under a skill value, `1d100 <= 60`, as a success level.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::system::{Coc, Dice};
+/// use walzecore::dice::{DiceRoll, GameSystem};
+///
+/// let mut roll = DiceRoll::new("1d100 <= 60", "[11] = 11".into(), Some(11));
+/// Coc.interpret(&mut roll, &[Dice { sides: 100, faces: vec![11] }]);
+/// assert_eq!(roll.description(), "[11] = 11\n**extreme success by 49**");
+/// assert!(roll.verdict.unwrap().success);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Coc;
+
+impl GameSystem for Coc {
+    fn name(&self) -> &'static str {
+        "coc"
+    }
+
+    fn description(&self) -> &'static str {
+        "Call of Cthulhu, d100 under a skill with regular, hard and extreme successes, use coc(skill, +bonus)"
+    }
+
+    fn interpret(&self, roll: &mut DiceRoll, _dice: &[Dice]) {
+        let (expr, target) = Target::split(&roll.expr);
+        let (Some(target), Some(100), Some(total)) = (target, leading_die(expr), roll.total) else {
+            return;
+        };
+        let (Ok(value), Ok(skill)) = (u64::try_from(total), u64::try_from(target.value)) else {
+            return;
+        };
+        roll.verdict = Some(verdict(value, skill));
+    }
+
+    fn is_own_syntax(&self, part: &str) -> bool {
+        !matches!(Check::parse(part), Ok(None))
+    }
+}
@@ -0,0 +1,237 @@
+use std::fmt::Write;
+
+use lazy_static::lazy_static;
+use rand::Rng;
+use regex::Regex;
+
+use crate::dice;
+use crate::dice::system::{Dice, GameSystem};
+use crate::dice::target::Target;
+use crate::dice::{DiceRoll, Result};
+
+lazy_static! {
+    /// Matches `wrath(<pool>[, <wrath dice>])`
+    static ref POOL_REGEX: Regex =
+        Regex::new(r"^\s*wrath\(\s*([0-9]+)\s*(?:,\s*([0-9]+)\s*)?\)\s*$").unwrap();
+}
+
+/// A Wrath & Glory dice pool, `wrath(7)`, of d6 where one die is the wrath die.
+///
+/// The number of wrath dice in the pool may follow the pool size, e.g. `wrath(7, 2)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pool {
+    /// Number of dice in the pool, wrath dice included.
+    pub dice: u64,
+    /// Number of wrath dice in the pool.
+    pub wrath: u64,
+}
+
+impl Pool {
+    /// Parses a pool expression.
+    ///
+    /// Returns `Ok(None)` if the expression is not a pool expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pool is empty or has more wrath dice than dice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::dice::system::wrath::Pool;
+    ///
+    /// assert_eq!(Pool::parse("wrath(7)")?, Some(Pool { dice: 7, wrath: 1 }));
+    /// assert_eq!(Pool::parse("wrath(5, 2)")?, Some(Pool { dice: 5, wrath: 2 }));
+    /// assert_eq!(Pool::parse("7d6")?, None);
+    /// assert!(Pool::parse("wrath(1, 2)").is_err());
+    /// # Ok::<(), self::walzecore::dice::Error>(())
+    /// ```
+    pub fn parse(expr: &str) -> Result<Option<Pool>> {
+        let Some(caps) = POOL_REGEX.captures(expr) else {
+            return Ok(None);
+        };
+        let dice = caps[1].parse().unwrap_or(u64::MAX);
+        let wrath = caps.get(2).map_or(Ok(1), |m| m.as_str().parse());
+        let wrath = wrath.unwrap_or(u64::MAX);
+
+        if dice == 0 {
+            return Err(dice::Error::Simple("a dice pool needs at least one die"));
+        }
+        if wrath > dice {
+            return Err(dice::Error::Simple(
+                "a dice pool cannot have more wrath dice than dice",
+            ));
+        }
+        Ok(Some(Pool { dice, wrath }))
+    }
+
+    /// Rolls the pool.
+    pub fn roll<R: Rng>(&self, rng: &mut R) -> PoolRoll {
+        let mut roll = || rng.gen_range(1..=6);
+        let wrath = (0..self.wrath).map(|_| roll()).collect();
+        let faces = (self.wrath..self.dice).map(|_| roll()).collect();
+        PoolRoll { faces, wrath }
+    }
+}
+
+/// The faces of a rolled [`Pool`].
+///
+/// A 4 or 5 is an icon, a 6 is an exalted icon worth two. A wrath die counts icons as well, and
+/// additionally causes a complication on a 1 and glory on a 6.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::system::wrath::PoolRoll;
+///
+/// let roll = PoolRoll { faces: vec![6, 6, 5, 2, 1, 4], wrath: vec![6] };
+/// assert_eq!((roll.icons(), roll.exalted()), (8, 3));
+/// assert!(roll.glory() && !roll.complication());
+///
+/// // only icons beyond the difficulty may be shifted, two for each exalted icon
+/// assert_eq!(roll.shifts(Some(4)), 2);
+/// assert_eq!(roll.shifts(Some(7)), 0);
+/// assert_eq!(roll.shifts(None), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolRoll {
+    pub faces: Vec<u64>,
+    pub wrath: Vec<u64>,
+}
+
+impl PoolRoll {
+    /// Returns the number of icons, counting exalted icons twice.
+    pub fn icons(&self) -> u64 {
+        self.all().map(icons).sum()
+    }
+
+    /// Returns the number of exalted icons.
+    pub fn exalted(&self) -> u64 {
+        self.all().filter(|&face| face == 6).count() as u64
+    }
+
+    /// Returns `true` if a wrath die rolled a 1.
+    pub fn complication(&self) -> bool {
+        self.wrath.contains(&1)
+    }
+
+    /// Returns `true` if a wrath die rolled a 6.
+    pub fn glory(&self) -> bool {
+        self.wrath.contains(&6)
+    }
+
+    /// Returns how many exalted icons can be shifted, e.g. into damage, while still meeting the
+    /// difficulty number.
+    pub fn shifts(&self, dn: Option<u64>) -> u64 {
+        let spare = self.icons().saturating_sub(dn.unwrap_or(0));
+        self.exalted().min(spare / 2)
+    }
+
+    /// Renders the dice, icons, shifts and wrath outcome.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::dice::system::wrath::PoolRoll;
+    ///
+    /// let roll = PoolRoll { faces: vec![6, 4, 2], wrath: vec![1] };
+    /// assert_eq!(
+    ///     roll.render(Some(2)),
+    ///     "pool: [6, 4, 2] wrath: [1]\n**3 icons** (1 exalted) vs DN 2\n0 shifts\n**complication!**"
+    /// );
+    /// ```
+    pub fn render(&self, dn: Option<u64>) -> String {
+        let mut out = format!("pool: {:?} wrath: {:?}", self.faces, self.wrath);
+        let _ = write!(
+            out,
+            "\n**{} icons** ({} exalted)",
+            self.icons(),
+            self.exalted()
+        );
+        if let Some(dn) = dn {
+            let _ = write!(out, " vs DN {dn}");
+        }
+
+        let shifts = self.shifts(dn);
+        match shifts {
+            0 => out.push_str("\n0 shifts"),
+            1 => out.push_str("\n1 shift (+1 ED to damage)"),
+            n => {
+                let _ = write!(out, "\n{n} shifts (+{n} ED to damage)");
+            }
+        }
+
+        if self.glory() {
+            out.push_str("\n**glory!**");
+        }
+        if self.complication() {
+            out.push_str("\n**complication!**");
+        }
+        out
+    }
+
+    fn all(&self) -> impl Iterator<Item = u64> + '_ {
+        self.faces.iter().chain(&self.wrath).copied()
+    }
+}
+
+/// Number of icons a single d6 is worth.
+fn icons(face: u64) -> u64 {
+    match face {
+        4 | 5 => 1,
+        6 => 2,
+        _ => 0,
+    }
+}
+
+/// Wrath & Glory, counting icons on every pool of d6.
+///
+/// Pools with a wrath die are rolled with a [`Pool`] expression, `wrath(7) vs 3`.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::dice::system::{Dice, Wrath};
+/// use walzecore::dice::{DiceRoll, GameSystem};
+///
+/// let mut roll = DiceRoll::new("4d6", "[6, 4, 3, 1] = 14".into(), Some(14));
+/// Wrath.interpret(&mut roll, &[Dice { sides: 6, faces: vec![6, 4, 3, 1] }]);
+/// assert_eq!(roll.result, "[6, 4, 3, 1] = 14\n**3 icons** (1 exalted)");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Wrath;
+
+impl GameSystem for Wrath {
+    fn name(&self) -> &'static str {
+        "wrath"
+    }
+
+    fn description(&self) -> &'static str {
+        "Wrath & Glory, icons on 4-5 and exalted icons on 6, use wrath(pool) vs DN for the wrath die"
+    }
+
+    fn interpret(&self, roll: &mut DiceRoll, dice: &[Dice]) {
+        let faces: Vec<u64> = dice
+            .iter()
+            .filter(|dice| dice.sides == 6)
+            .flat_map(|dice| dice.faces.iter().copied())
+            .collect();
+        if faces.is_empty() {
+            return;
+        }
+        let pool = PoolRoll {
+            faces,
+            wrath: Vec::new(),
+        };
+        let _ = write!(
+            roll.result,
+            "\n**{} icons** ({} exalted)",
+            pool.icons(),
+            pool.exalted()
+        );
+    }
+
+    fn is_own_syntax(&self, part: &str) -> bool {
+        !matches!(Pool::parse(Target::split(part).0), Ok(None))
+    }
+}
@@ -0,0 +1,172 @@
+use std::fmt;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    /// Matches `<expr> vs <n>` and `<expr> <op> <n>`
+    static ref TARGET_REGEX: Regex =
+        Regex::new(r"^(.+?)\s*(\bvs\.?|>=|<=|==|>|<)\s*(-?[0-9]+)\s*$").unwrap();
+}
+
+/// How a total is compared against its target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    /// `vs` and `>=`
+    AtLeast,
+    /// `>`
+    Greater,
+    /// `<=`
+    AtMost,
+    /// `<`
+    Less,
+    /// `==`
+    Equal,
+}
+
+/// A target number or DC a roll has to meet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Target {
+    pub comparison: Comparison,
+    pub value: i64,
+}
+
+impl Target {
+    /// Splits an expression into the dice to roll and its target, if it has one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::dice::target::{Comparison, Target};
+    ///
+    /// let (expr, target) = Target::split("1d20+5 vs 15");
+    /// assert_eq!(expr, "1d20+5");
+    /// assert_eq!(target, Some(Target { comparison: Comparison::AtLeast, value: 15 }));
+    ///
+    /// let (expr, target) = Target::split("3d6 < 10");
+    /// assert_eq!((expr, target.unwrap().comparison), ("3d6", Comparison::Less));
+    ///
+    /// assert_eq!(Target::split("2d6+4"), ("2d6+4", None));
+    /// ```
+    pub fn split(expr: &str) -> (&str, Option<Target>) {
+        let Some(caps) = TARGET_REGEX.captures(expr) else {
+            return (expr, None);
+        };
+        let Ok(value) = caps[3].parse() else {
+            return (expr, None);
+        };
+        let comparison = match &caps[2] {
+            ">" => Comparison::Greater,
+            "<=" => Comparison::AtMost,
+            "<" => Comparison::Less,
+            "==" => Comparison::Equal,
+            _ => Comparison::AtLeast,
+        };
+        let dice = caps.get(1).map_or(expr, |m| m.as_str());
+
+        (dice, Some(Target { comparison, value }))
+    }
+
+    /// Checks a total against the target.
+    ///
+    /// `natural` is the face and the number of sides of the die driving the roll, e.g. the d20
+    /// of `1d20+5`, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::dice::target::{Natural, Target};
+    ///
+    /// let (_, target) = Target::split("1d20+5 vs 15");
+    /// let verdict = target.unwrap().check(22, Some((17, 20)));
+    /// assert!(verdict.success);
+    /// assert_eq!(verdict.margin, 7);
+    /// assert_eq!(verdict.natural, None);
+    ///
+    /// let verdict = target.unwrap().check(25, Some((20, 20)));
+    /// assert_eq!(verdict.natural, Some(Natural::Max));
+    /// assert_eq!(verdict.to_string(), "success by 10 (natural 20)");
+    /// ```
+    pub fn check(&self, total: i64, natural: Option<(u64, u64)>) -> Verdict {
+        let success = match self.comparison {
+            Comparison::AtLeast => total >= self.value,
+            Comparison::Greater => total > self.value,
+            Comparison::AtMost => total <= self.value,
+            Comparison::Less => total < self.value,
+            Comparison::Equal => total == self.value,
+        };
+        let margin = (total - self.value).abs();
+        let natural = natural.and_then(|(face, sides)| Some((Natural::of(face, sides)?, face)));
+
+        Verdict {
+            success,
+            margin,
+            natural: natural.map(|(natural, _)| natural),
+            face: natural.map(|(_, face)| face),
+            outcome: None,
+        }
+    }
+}
+
+/// A natural maximum or minimum on the die that drove a roll.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Natural {
+    Max,
+    Min,
+}
+
+impl Natural {
+    /// Returns whether a face is the natural maximum or minimum of a die.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::dice::target::Natural;
+    ///
+    /// assert_eq!(Natural::of(20, 20), Some(Natural::Max));
+    /// assert_eq!(Natural::of(1, 20), Some(Natural::Min));
+    /// assert_eq!(Natural::of(12, 20), None);
+    /// ```
+    pub fn of(face: u64, sides: u64) -> Option<Natural> {
+        match face {
+            1 => Some(Natural::Min),
+            f if f == sides => Some(Natural::Max),
+            _ => None,
+        }
+    }
+}
+
+/// The outcome of checking a total against its [`Target`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Verdict {
+    pub success: bool,
+    /// How far the total was from the target.
+    pub margin: i64,
+    pub natural: Option<Natural>,
+    /// The face of the die when it rolled a natural maximum or minimum.
+    pub face: Option<u64>,
+    /// How the game names the outcome, written instead of success or failure.
+    pub outcome: Option<&'static str>,
+}
+
+impl Verdict {
+    /// Returns `true` on a successful roll with a natural maximum.
+    pub fn is_critical(&self) -> bool {
+        self.success && self.natural == Some(Natural::Max)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let outcome = match (self.outcome, self.success) {
+            (Some(outcome), _) => outcome,
+            (None, true) => "success",
+            (None, false) => "failure",
+        };
+        write!(f, "{outcome} by {}", self.margin)?;
+        if let Some(face) = self.face {
+            write!(f, " (natural {face})")?;
+        }
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
+pub mod calendar;
 pub mod db;
+pub mod dice;
+pub mod schedule;
 pub mod tz;
 
 pub fn add(left: usize, right: usize) -> usize {
@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+use std::result;
+
+pub type Result<T> = result::Result<T, Error>;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("could not understand the recurrence \"{0}\", try weekly, biweekly, monthly, \"2nd thursday\" or an RRULE")]
+    InvalidRule(String),
+    #[error("could not understand the reminder \"{0}\", try \"1d, 2h, 15m\"")]
+    InvalidLead(String),
+    #[error("there is no session on {0}")]
+    NoSession(String),
+    #[error("schedule {0} not found")]
+    ScheduleNotFound(u64),
+    #[error("invalid schedules: {0}")]
+    InvalidSchedules(#[from] serde_json::Error),
+    #[error("{0}")]
+    Simple(&'static str),
+}
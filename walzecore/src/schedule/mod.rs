@@ -0,0 +1,499 @@
+pub mod error;
+pub mod poll;
+pub mod rule;
+
+pub use error::Error;
+pub use error::Result;
+pub use rule::Recurrence;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+use crate::tz;
+use crate::tz::dst;
+
+/// Reminders sent before a session when none are given, a day and an hour before.
+pub const DEFAULT_LEADS: [u32; 2] = [24 * 60, 60];
+
+/// How many occurrences are looked through at most when searching sessions.
+const MAX_OCCURRENCES: usize = 1000;
+
+/// A campaign meeting on a recurring schedule.
+///
+/// Sessions are anchored at a local time in the organizer's zone, so they stay at the same
+/// local time across daylight saving time changes.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, TimeZone, Utc};
+/// use walzecore::schedule::{Recurrence, Schedule};
+///
+/// let first = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap().and_hms_opt(19, 0, 0).unwrap();
+/// let rule = Recurrence::parse("biweekly", first.date())?;
+/// let schedule = Schedule::new("Curse of Strahd", 1, 2, 3, "Europe/Berlin", first, rule);
+///
+/// // Berlin leaves summer time on 25 October, the sessions stay at 19:00 local time
+/// let from = Utc.with_ymd_and_hms(2026, 10, 1, 0, 0, 0).unwrap();
+/// let sessions: Vec<String> = schedule
+///     .upcoming(from, 2)
+///     .iter()
+///     .map(|session| session.at.to_utc().format("%d/%m %H:%M").to_string())
+///     .collect();
+/// assert_eq!(sessions, ["15/10 17:00", "29/10 18:00"]);
+/// # Ok::<(), self::walzecore::schedule::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Schedule {
+    pub title: String,
+    pub guild: u64,
+    pub channel: u64,
+    pub organizer: u64,
+    zone: String,
+    first: NaiveDateTime,
+    #[serde(with = "rrule")]
+    rule: Recurrence,
+    leads: Vec<u32>,
+    #[serde(default)]
+    skipped: BTreeSet<NaiveDate>,
+    #[serde(default)]
+    moved: BTreeMap<NaiveDate, NaiveDateTime>,
+}
+
+/// One session of a schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Session {
+    /// The date the session has by the schedule's rule, which names it even when moved.
+    pub date: NaiveDate,
+    pub at: DateTime<Tz>,
+    pub moved: bool,
+}
+
+/// A reminder that is due for a session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reminder {
+    pub id: u64,
+    pub session: Session,
+    /// Minutes before the session.
+    pub lead: u32,
+}
+
+impl Schedule {
+    /// Creates a schedule whose first session is at the local time `first` in `zone`,
+    /// reminding [`DEFAULT_LEADS`] before each session.
+    pub fn new<T: Into<String>>(
+        title: T,
+        guild: u64,
+        channel: u64,
+        organizer: u64,
+        zone: &str,
+        first: NaiveDateTime,
+        rule: Recurrence,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            guild,
+            channel,
+            organizer,
+            zone: zone.to_string(),
+            first,
+            rule,
+            leads: DEFAULT_LEADS.to_vec(),
+            skipped: BTreeSet::new(),
+            moved: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the zone the sessions are anchored in.
+    pub fn zone(&self) -> Tz {
+        self.zone.parse().unwrap_or(Tz::UTC)
+    }
+
+    /// Returns how the schedule repeats.
+    pub fn rule(&self) -> Recurrence {
+        self.rule
+    }
+
+    /// Returns how many minutes before a session reminders are sent, longest first.
+    pub fn leads(&self) -> &[u32] {
+        &self.leads
+    }
+
+    /// Sets how many minutes before a session reminders are sent.
+    pub fn leads_mut(&mut self, mut leads: Vec<u32>) {
+        leads.sort_unstable_by(|a, b| b.cmp(a));
+        leads.dedup();
+        self.leads = leads;
+    }
+
+    /// Cancels the session on `date`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schedule has no session on that date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate, TimeZone, Utc};
+    /// use walzecore::schedule::{Recurrence, Schedule};
+    ///
+    /// let date = |d| NaiveDate::from_ymd_opt(2026, 11, d).unwrap();
+    /// let first = date(5).and_hms_opt(19, 0, 0).unwrap();
+    /// let rule = Recurrence::parse("weekly", date(5))?;
+    /// let mut schedule = Schedule::new("Blades", 1, 2, 3, "UTC", first, rule);
+    ///
+    /// schedule.skip(date(12))?;
+    /// assert!(schedule.skip(date(13)).is_err());
+    ///
+    /// let from = Utc.with_ymd_and_hms(2026, 11, 1, 0, 0, 0).unwrap();
+    /// let days: Vec<u32> = schedule.upcoming(from, 3).iter().map(|s| s.date.day()).collect();
+    /// assert_eq!(days, [5, 19, 26]);
+    /// # Ok::<(), self::walzecore::schedule::Error>(())
+    /// ```
+    pub fn skip(&mut self, date: NaiveDate) -> Result<()> {
+        self.occurs(date)?;
+        self.moved.remove(&date);
+        self.skipped.insert(date);
+        Ok(())
+    }
+
+    /// Moves the session on `date` to the local time `to`, or back to its time when it is the
+    /// same date and time the rule gives.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schedule has no session on that date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, TimeZone, Utc};
+    /// use walzecore::schedule::{Recurrence, Schedule};
+    ///
+    /// let date = |d| NaiveDate::from_ymd_opt(2026, 11, d).unwrap();
+    /// let first = date(5).and_hms_opt(19, 0, 0).unwrap();
+    /// let rule = Recurrence::parse("weekly", date(5))?;
+    /// let mut schedule = Schedule::new("Blades", 1, 2, 3, "UTC", first, rule);
+    ///
+    /// // the session of the 19th takes place before the one of the 12th
+    /// schedule.reschedule(date(19), date(10).and_hms_opt(18, 0, 0).unwrap())?;
+    /// let from = Utc.with_ymd_and_hms(2026, 11, 6, 0, 0, 0).unwrap();
+    /// let sessions: Vec<String> = schedule
+    ///     .upcoming(from, 3)
+    ///     .iter()
+    ///     .map(|s| s.at.format("%d %H:%M").to_string())
+    ///     .collect();
+    /// assert_eq!(sessions, ["10 18:00", "12 19:00", "26 19:00"]);
+    /// # Ok::<(), self::walzecore::schedule::Error>(())
+    /// ```
+    pub fn reschedule(&mut self, date: NaiveDate, to: NaiveDateTime) -> Result<()> {
+        self.occurs(date)?;
+        self.skipped.remove(&date);
+        if to == date.and_time(self.first.time()) {
+            self.moved.remove(&date);
+        } else {
+            self.moved.insert(date, to);
+        }
+        Ok(())
+    }
+
+    /// Returns the next `count` sessions starting at or after `from`, in the order they take
+    /// place.
+    pub fn upcoming(&self, from: DateTime<Utc>, count: usize) -> Vec<Session> {
+        let last_moved = self.moved.keys().next_back().copied();
+        let mut sessions = Vec::new();
+        let mut on_time = 0;
+        for session in self.sessions() {
+            // a session moved from later on can take place before the ones found so far
+            let done =
+                on_time >= count && last_moved.map(|last| session.date > last).unwrap_or(true);
+            if done {
+                break;
+            }
+            if session.at.to_utc() >= from {
+                on_time += usize::from(!session.moved);
+                sessions.push(session);
+            }
+        }
+        sessions.sort_by_key(|session| session.at);
+        sessions.truncate(count);
+        sessions
+    }
+
+    /// Returns the reminders due after `since` up to `now`.
+    pub fn due(&self, id: u64, since: DateTime<Utc>, now: DateTime<Utc>) -> Vec<Reminder> {
+        let longest = Duration::minutes(i64::from(self.leads.first().copied().unwrap_or(0)));
+        let last_moved = self.moved.keys().next_back().copied();
+        let horizon = (now + longest).date_naive() + Duration::days(1);
+
+        let mut reminders = Vec::new();
+        for session in self.sessions() {
+            if session.date > horizon && last_moved.map(|last| session.date > last).unwrap_or(true)
+            {
+                break;
+            }
+            for lead in &self.leads {
+                let at = session.at.to_utc() - Duration::minutes(i64::from(*lead));
+                if since < at && at <= now {
+                    reminders.push(Reminder {
+                        id,
+                        session,
+                        lead: *lead,
+                    });
+                }
+            }
+        }
+        reminders
+    }
+
+    /// Returns every session the rule gives, skipped ones left out and moved ones at their
+    /// new time, ordered by the date the rule gives them.
+    fn sessions(&self) -> impl Iterator<Item = Session> + '_ {
+        let zone = self.zone();
+        self.rule
+            .dates(self.first.date())
+            .take(MAX_OCCURRENCES)
+            .filter(|date| !self.skipped.contains(date))
+            .filter_map(move |date| {
+                let (local, moved) = match self.moved.get(&date) {
+                    Some(local) => (*local, true),
+                    None => (date.and_time(self.first.time()), false),
+                };
+                Some(Session {
+                    date,
+                    at: dst::resolve_forward(zone, local)?,
+                    moved,
+                })
+            })
+    }
+
+    fn occurs(&self, date: NaiveDate) -> Result<()> {
+        self.rule
+            .dates(self.first.date())
+            .take(MAX_OCCURRENCES)
+            .take_while(|other| *other <= date)
+            .any(|other| other == date)
+            .then_some(())
+            .ok_or_else(|| Error::NoSession(date.format("%a %d %b %Y").to_string()))
+    }
+}
+
+/// Parses reminder lead times like `1d, 2h, 15m` into minutes.
+///
+/// # Errors
+///
+/// Returns an error naming the lead time that is not understood.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::schedule::parse_leads;
+///
+/// assert_eq!(parse_leads("1d, 2h, 15m")?, [1440, 120, 15]);
+/// assert_eq!(parse_leads("1 day 2 hours")?, [1560]);
+/// assert!(parse_leads("soon").is_err());
+/// # Ok::<(), self::walzecore::schedule::Error>(())
+/// ```
+pub fn parse_leads(input: &str) -> Result<Vec<u32>> {
+    input
+        .split(',')
+        .map(str::trim)
+        .filter(|lead| !lead.is_empty())
+        .map(|lead| {
+            tz::natural::parse_span(&lead.to_lowercase())
+                .and_then(|span| u32::try_from(span.num_minutes()).ok())
+                .filter(|minutes| *minutes > 0)
+                .ok_or_else(|| Error::InvalidLead(lead.to_string()))
+        })
+        .collect()
+}
+
+/// Every schedule, keyed by its id.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, TimeZone, Utc};
+/// use walzecore::schedule::{Recurrence, Schedule, Schedules};
+///
+/// let first = NaiveDate::from_ymd_opt(2026, 11, 5).unwrap().and_hms_opt(19, 0, 0).unwrap();
+/// let rule = Recurrence::parse("weekly", first.date())?;
+/// let mut schedules = Schedules::new("{}")?;
+/// let id = schedules.add(Schedule::new("Blades", 1, 2, 3, "UTC", first, rule));
+///
+/// // an hour before the first session
+/// let since = Utc.with_ymd_and_hms(2026, 11, 5, 17, 59, 0).unwrap();
+/// let now = Utc.with_ymd_and_hms(2026, 11, 5, 18, 0, 0).unwrap();
+/// let due = schedules.due(since, now);
+/// assert_eq!((due[0].id, due[0].lead), (id, 60));
+///
+/// let schedules = Schedules::new(&schedules.to_json())?;
+/// assert_eq!(schedules.get(id).map(|s| s.title.as_str()), Some("Blades"));
+/// # Ok::<(), self::walzecore::schedule::Error>(())
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Schedules {
+    #[serde(default)]
+    next_id: u64,
+    #[serde(default)]
+    schedules: BTreeMap<u64, Schedule>,
+}
+
+impl Schedules {
+    /// Creates the schedules from a JSON string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON cannot be deserialized, so corrupt schedules are never
+    /// replaced by empty ones and written back over the file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::schedule::Schedules;
+    ///
+    /// assert!(Schedules::new("{}")?.get(1).is_none());
+    /// assert!(Schedules::new("{\"schedules\": 3}").is_err());
+    /// # Ok::<(), self::walzecore::schedule::Error>(())
+    /// ```
+    pub fn new(json: &str) -> Result<Schedules> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Converts the schedules to a JSON string.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// Adds a schedule, returning its id.
+    pub fn add(&mut self, schedule: Schedule) -> u64 {
+        self.next_id += 1;
+        self.schedules.insert(self.next_id, schedule);
+        self.next_id
+    }
+
+    /// Returns the schedule with the id.
+    pub fn get(&self, id: u64) -> Option<&Schedule> {
+        self.schedules.get(&id)
+    }
+
+    /// Returns the schedule with the id.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no schedule with that id.
+    pub fn get_mut(&mut self, id: u64) -> Result<&mut Schedule> {
+        self.schedules
+            .get_mut(&id)
+            .ok_or(Error::ScheduleNotFound(id))
+    }
+
+    /// Removes the schedule with the id.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no schedule with that id.
+    pub fn remove(&mut self, id: u64) -> Result<Schedule> {
+        self.schedules
+            .remove(&id)
+            .ok_or(Error::ScheduleNotFound(id))
+    }
+
+    /// Returns the schedules of a guild with their ids.
+    pub fn of_guild(&self, guild: u64) -> impl Iterator<Item = (u64, &Schedule)> {
+        self.schedules
+            .iter()
+            .filter(move |(_, schedule)| schedule.guild == guild)
+            .map(|(id, schedule)| (*id, schedule))
+    }
+
+    /// Returns the reminders of every schedule due after `since` up to `now`.
+    pub fn due(&self, since: DateTime<Utc>, now: DateTime<Utc>) -> Vec<Reminder> {
+        self.schedules
+            .iter()
+            .flat_map(|(id, schedule)| schedule.due(*id, since, now))
+            .collect()
+    }
+}
+
+/// Stores a recurrence as its RRULE.
+mod rrule {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::Recurrence;
+
+    pub fn serialize<S: Serializer>(rule: &Recurrence, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(rule)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Recurrence, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, TimeZone};
+
+    use super::*;
+
+    fn local(month: u32, day: u32, hour: u32, min: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, month, day)
+            .unwrap()
+            .and_hms_opt(hour, min, 0)
+            .unwrap()
+    }
+
+    fn utc(month: u32, day: u32, hour: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, month, day, hour, min, 0)
+            .unwrap()
+    }
+
+    fn weekly(first: NaiveDateTime) -> Schedule {
+        let rule = Recurrence::parse("weekly", first.date()).unwrap();
+        let mut schedule = Schedule::new("Blades", 1, 2, 3, "Europe/Berlin", first, rule);
+        schedule.leads_mut(vec![60]);
+        schedule
+    }
+
+    #[test]
+    fn due_keeps_the_local_time_after_the_clocks_go_back() {
+        // 19:00 in Berlin is 17:00 UTC in summer time and 18:00 UTC after 25 October
+        let schedule = weekly(local(10, 17, 19, 0));
+
+        let before = schedule.due(1, utc(10, 24, 15, 59), utc(10, 24, 16, 0));
+        assert_eq!(before.len(), 1);
+        assert_eq!(before[0].session.date, local(10, 24, 0, 0).date());
+
+        assert!(schedule
+            .due(1, utc(10, 31, 15, 59), utc(10, 31, 16, 0))
+            .is_empty());
+        let after = schedule.due(1, utc(10, 31, 16, 59), utc(10, 31, 17, 0));
+        assert_eq!(after.len(), 1);
+        assert_eq!(after[0].session.date, local(10, 31, 0, 0).date());
+    }
+
+    #[test]
+    fn due_moves_a_skipped_local_time_forward() {
+        // 02:30 does not exist on 29 March, the session starts at 03:00 summer time instead
+        let schedule = weekly(local(3, 22, 2, 30));
+        let due = schedule.due(1, utc(3, 28, 23, 59), utc(3, 29, 0, 0));
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].session.at.to_utc(), utc(3, 29, 1, 0));
+    }
+
+    #[test]
+    fn due_reminds_once_for_a_repeated_local_time() {
+        // 02:30 happens twice on 25 October, the session takes the first one
+        let schedule = weekly(local(10, 18, 2, 30));
+        let due = schedule.due(1, utc(10, 24, 12, 0), utc(10, 25, 12, 0));
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].session.at.to_utc(), utc(10, 25, 0, 30));
+    }
+}
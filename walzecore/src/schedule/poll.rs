@@ -0,0 +1,177 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+
+use crate::schedule::{Error, Result};
+
+/// Most candidate times a poll can have.
+pub const MAX_OPTIONS: usize = 4;
+
+/// A member's answer for one candidate time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Vote {
+    Available,
+    Maybe,
+    No,
+}
+
+impl Vote {
+    pub const ALL: [Vote; 3] = [Vote::Available, Vote::Maybe, Vote::No];
+
+    /// The short name of the vote, used to tell the buttons apart.
+    pub fn name(self) -> &'static str {
+        match self {
+            Vote::Available => "yes",
+            Vote::Maybe => "maybe",
+            Vote::No => "no",
+        }
+    }
+
+    /// Looks up a vote by its short name.
+    pub fn by_name(name: &str) -> Option<Vote> {
+        Vote::ALL.into_iter().find(|vote| vote.name() == name)
+    }
+}
+
+impl fmt::Display for Vote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Vote::Available => write!(f, "available"),
+            Vote::Maybe => write!(f, "maybe"),
+            Vote::No => write!(f, "not available"),
+        }
+    }
+}
+
+/// How a candidate time was voted for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Tally {
+    pub available: usize,
+    pub maybe: usize,
+    pub no: usize,
+}
+
+impl Tally {
+    /// Ranks the time, an available vote counts twice as much as a maybe.
+    pub fn score(&self) -> usize {
+        self.available * 2 + self.maybe
+    }
+}
+
+impl fmt::Display for Tally {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} available, {} maybe, {} no",
+            self.available, self.maybe, self.no
+        )
+    }
+}
+
+/// A poll for the time of a session.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use walzecore::schedule::poll::{Poll, Vote};
+///
+/// let at = |day| Utc.with_ymd_and_hms(2026, 11, day, 18, 0, 0).unwrap();
+/// let mut poll = Poll::new(vec![at(5), at(6), at(7)])?;
+///
+/// poll.vote(1, 0, Vote::Maybe)?;
+/// poll.vote(1, 1, Vote::Available)?;
+/// poll.vote(2, 1, Vote::Maybe)?;
+/// poll.vote(2, 2, Vote::Available)?;
+/// poll.vote(3, 2, Vote::Available)?;
+/// assert_eq!(poll.winner(), Some(2));
+///
+/// // changing a vote replaces it
+/// poll.vote(3, 2, Vote::No)?;
+/// assert_eq!(poll.tally(2).to_string(), "1 available, 0 maybe, 1 no");
+/// // equal scores go to the option with more available votes, then to the earlier one
+/// assert_eq!(poll.winner(), Some(1));
+///
+/// assert!(poll.vote(1, 3, Vote::No).is_err());
+/// # Ok::<(), self::walzecore::schedule::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Poll {
+    options: Vec<DateTime<Utc>>,
+    votes: BTreeMap<(u64, usize), Vote>,
+}
+
+impl Poll {
+    /// Creates a poll over the candidate times, sorted by when they are.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no or more than [`MAX_OPTIONS`] times.
+    pub fn new(mut options: Vec<DateTime<Utc>>) -> Result<Poll> {
+        options.sort_unstable();
+        options.dedup();
+        if options.is_empty() {
+            return Err(Error::Simple("a poll needs at least one time"));
+        }
+        if options.len() > MAX_OPTIONS {
+            return Err(Error::Simple("a poll can have at most 4 times"));
+        }
+        Ok(Poll {
+            options,
+            votes: BTreeMap::new(),
+        })
+    }
+
+    /// Returns the candidate times.
+    pub fn options(&self) -> &[DateTime<Utc>] {
+        &self.options
+    }
+
+    /// Records a voter's answer for an option, replacing an earlier one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no such option.
+    pub fn vote(&mut self, voter: u64, option: usize, vote: Vote) -> Result<()> {
+        if option >= self.options.len() {
+            return Err(Error::Simple("there is no such time in the poll"));
+        }
+        self.votes.insert((voter, option), vote);
+        Ok(())
+    }
+
+    /// Returns everyone who voted, ordered by id.
+    pub fn voters(&self) -> Vec<u64> {
+        let mut voters: Vec<u64> = self.votes.keys().map(|(voter, _)| *voter).collect();
+        voters.dedup();
+        voters
+    }
+
+    /// Returns how an option was voted for.
+    pub fn tally(&self, option: usize) -> Tally {
+        self.votes
+            .iter()
+            .filter(|((_, other), _)| *other == option)
+            .fold(Tally::default(), |mut tally, (_, vote)| {
+                match vote {
+                    Vote::Available => tally.available += 1,
+                    Vote::Maybe => tally.maybe += 1,
+                    Vote::No => tally.no += 1,
+                }
+                tally
+            })
+    }
+
+    /// Returns the option with the best score, or `None` if nobody is available or maybe
+    /// available at any of them.
+    pub fn winner(&self) -> Option<usize> {
+        (0..self.options.len())
+            .map(|option| (option, self.tally(option)))
+            .filter(|(_, tally)| tally.score() > 0)
+            .max_by_key(|(option, tally)| {
+                (tally.score(), tally.available, std::cmp::Reverse(*option))
+            })
+            .map(|(option, _)| option)
+    }
+}
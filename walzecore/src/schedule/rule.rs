@@ -0,0 +1,299 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{Datelike, Duration, Months, NaiveDate, Weekday};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::schedule::{Error, Result};
+
+lazy_static! {
+    /// Matches `every 3 weeks`
+    static ref EVERY_REGEX: Regex = Regex::new(r"^every\s+([0-9]+)\s+weeks?$").unwrap();
+    /// Matches `2nd thursday` and `last friday`, optionally followed by `of the month`
+    static ref NTH_REGEX: Regex = Regex::new(
+        r"^(?:the\s+)?(first|1st|second|2nd|third|3rd|fourth|4th|fifth|5th|last)\s+([a-z]+)(?:\s+of\s+(?:the|every)\s+month)?$"
+    )
+    .unwrap();
+}
+
+/// How a schedule repeats, a subset of the iCalendar RRULE (RFC 5545).
+///
+/// Serialized as its RRULE, e.g. `FREQ=WEEKLY;INTERVAL=2;BYDAY=TH`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Recurrence {
+    /// Every `interval` weeks on the weekday.
+    Weekly { interval: u32, weekday: Weekday },
+    /// On the `nth` weekday of every month, `-1` being the last one.
+    Monthly { nth: i8, weekday: Weekday },
+}
+
+impl Recurrence {
+    /// Parses a recurrence written as `weekly`, `biweekly`, `every 3 weeks`, `monthly`,
+    /// `2nd thursday`, `last friday` or as an RRULE.
+    ///
+    /// Rules without a weekday take the weekday of the first session, `monthly` also takes
+    /// which one of the month it is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the recurrence is not understood.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use walzecore::schedule::rule::Recurrence;
+    ///
+    /// // Thursday 12 November 2026, the second Thursday of the month
+    /// let first = NaiveDate::from_ymd_opt(2026, 11, 12).unwrap();
+    /// let parse = |input| Recurrence::parse(input, first).map(|rule| rule.to_string());
+    ///
+    /// assert_eq!(parse("weekly")?, "FREQ=WEEKLY;INTERVAL=1;BYDAY=TH");
+    /// assert_eq!(parse("biweekly")?, "FREQ=WEEKLY;INTERVAL=2;BYDAY=TH");
+    /// assert_eq!(parse("every 3 weeks")?, "FREQ=WEEKLY;INTERVAL=3;BYDAY=TH");
+    /// assert_eq!(parse("monthly")?, "FREQ=MONTHLY;BYDAY=2TH");
+    /// assert_eq!(parse("last friday")?, "FREQ=MONTHLY;BYDAY=-1FR");
+    /// assert_eq!(parse("RRULE:FREQ=MONTHLY;BYDAY=SA;BYSETPOS=1")?, "FREQ=MONTHLY;BYDAY=1SA");
+    /// assert!(parse("daily").is_err());
+    /// # Ok::<(), self::walzecore::schedule::Error>(())
+    /// ```
+    pub fn parse(input: &str, first: NaiveDate) -> Result<Recurrence> {
+        let text = input.trim().to_lowercase();
+        let weekday = first.weekday();
+        let weekly = |interval| Ok(Recurrence::Weekly { interval, weekday });
+
+        match text.as_str() {
+            "weekly" | "every week" => return weekly(1),
+            "biweekly" | "fortnightly" | "every other week" => return weekly(2),
+            "monthly" | "every month" => {
+                // the fifth weekday is not in every month, the last one is
+                let nth = match (first.day() - 1) / 7 + 1 {
+                    5 => -1,
+                    nth => i8::try_from(nth).unwrap_or(-1),
+                };
+                return Ok(Recurrence::Monthly { nth, weekday });
+            }
+            _ => {}
+        }
+        if let Some(caps) = EVERY_REGEX.captures(&text) {
+            return match caps[1].parse() {
+                Ok(interval) if interval > 0 => weekly(interval),
+                _ => Err(Error::InvalidRule(input.to_string())),
+            };
+        }
+        if let Some(caps) = NTH_REGEX.captures(&text) {
+            let nth = match &caps[1] {
+                "first" | "1st" => 1,
+                "second" | "2nd" => 2,
+                "third" | "3rd" => 3,
+                "fourth" | "4th" => 4,
+                "fifth" | "5th" => 5,
+                _ => -1,
+            };
+            let weekday = caps[2]
+                .trim_end_matches('s')
+                .parse()
+                .map_err(|_| Error::InvalidRule(input.to_string()))?;
+            return Ok(Recurrence::Monthly { nth, weekday });
+        }
+        input.parse()
+    }
+
+    /// Returns the dates of the sessions from the first one on, starting with the first date
+    /// on or after `first` that matches the rule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use walzecore::schedule::rule::Recurrence;
+    ///
+    /// let first = NaiveDate::from_ymd_opt(2026, 11, 12).unwrap();
+    /// let dates = |rule: Recurrence| -> Vec<String> {
+    ///     rule.dates(first).take(3).map(|date| date.format("%d/%m").to_string()).collect()
+    /// };
+    ///
+    /// let biweekly = Recurrence::Weekly { interval: 2, weekday: Weekday::Thu };
+    /// assert_eq!(dates(biweekly), ["12/11", "26/11", "10/12"]);
+    /// let last_friday = Recurrence::Monthly { nth: -1, weekday: Weekday::Fri };
+    /// assert_eq!(dates(last_friday), ["27/11", "25/12", "29/01"]);
+    /// let fifth_monday = Recurrence::Monthly { nth: 5, weekday: Weekday::Mon };
+    /// assert_eq!(dates(fifth_monday), ["30/11", "29/03", "31/05"]);
+    /// ```
+    pub fn dates(self, first: NaiveDate) -> Box<dyn Iterator<Item = NaiveDate> + Send> {
+        match self {
+            Recurrence::Weekly { interval, weekday } => {
+                let ahead = (7 + weekday.num_days_from_monday()
+                    - first.weekday().num_days_from_monday())
+                    % 7;
+                let start = first + Duration::days(i64::from(ahead));
+                let step = Duration::weeks(i64::from(interval));
+                Box::new(
+                    (0..).map_while(move |n: i32| start.checked_add_signed(step.checked_mul(n)?)),
+                )
+            }
+            Recurrence::Monthly { nth, weekday } => {
+                let month = first.with_day(1).unwrap_or(first);
+                Box::new(
+                    (0..)
+                        .map_while(move |n| month.checked_add_months(Months::new(n)))
+                        .filter_map(move |month| nth_weekday(month, nth, weekday))
+                        .filter(move |date| *date >= first),
+                )
+            }
+        }
+    }
+
+    /// Describes the rule in plain words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    /// use walzecore::schedule::rule::Recurrence;
+    ///
+    /// let rule = Recurrence::Weekly { interval: 2, weekday: Weekday::Thu };
+    /// assert_eq!(rule.describe(), "every other Thursday");
+    /// let rule = Recurrence::Monthly { nth: 2, weekday: Weekday::Sat };
+    /// assert_eq!(rule.describe(), "the 2nd Saturday of every month");
+    /// ```
+    pub fn describe(&self) -> String {
+        match self {
+            Recurrence::Weekly {
+                interval: 1,
+                weekday,
+            } => format!("every {}", day_name(*weekday)),
+            Recurrence::Weekly {
+                interval: 2,
+                weekday,
+            } => {
+                format!("every other {}", day_name(*weekday))
+            }
+            Recurrence::Weekly { interval, weekday } => {
+                format!("every {interval} weeks on {}", day_name(*weekday))
+            }
+            Recurrence::Monthly { nth, weekday } => {
+                let nth = match nth {
+                    1 => "1st",
+                    2 => "2nd",
+                    3 => "3rd",
+                    4 => "4th",
+                    5 => "5th",
+                    _ => "last",
+                };
+                format!("the {nth} {} of every month", day_name(*weekday))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Recurrence {
+    /// Writes the rule as an RRULE value.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Recurrence::Weekly { interval, weekday } => write!(
+                f,
+                "FREQ=WEEKLY;INTERVAL={interval};BYDAY={}",
+                day_code(*weekday)
+            ),
+            Recurrence::Monthly { nth, weekday } => {
+                write!(f, "FREQ=MONTHLY;BYDAY={nth}{}", day_code(*weekday))
+            }
+        }
+    }
+}
+
+impl FromStr for Recurrence {
+    type Err = Error;
+
+    /// Parses an RRULE with a weekly or monthly `FREQ` and a single `BYDAY`.
+    fn from_str(input: &str) -> Result<Self> {
+        let invalid = || Error::InvalidRule(input.to_string());
+        let rule = input.trim().to_uppercase();
+        let rule = rule.strip_prefix("RRULE:").unwrap_or(&rule);
+
+        let (mut freq, mut interval, mut byday, mut setpos) = (None, 1, None, None);
+        for part in rule.split(';').filter(|part| !part.is_empty()) {
+            let (key, value) = part.split_once('=').ok_or_else(invalid)?;
+            match key {
+                "FREQ" => freq = Some(value.to_string()),
+                "INTERVAL" => interval = value.parse().map_err(|_| invalid())?,
+                "BYDAY" => byday = Some(value.to_string()),
+                "BYSETPOS" => setpos = Some(value.parse::<i8>().map_err(|_| invalid())?),
+                "WKST" => {}
+                _ => return Err(invalid()),
+            }
+        }
+
+        let byday = byday.ok_or_else(invalid)?;
+        let split = byday.len().checked_sub(2).ok_or_else(invalid)?;
+        let (nth, code) = byday.split_at(split);
+        let weekday = weekday_of(code).ok_or_else(invalid)?;
+        match freq.as_deref() {
+            Some("WEEKLY") if nth.is_empty() && interval > 0 => {
+                Ok(Recurrence::Weekly { interval, weekday })
+            }
+            Some("MONTHLY") if interval == 1 => {
+                let nth = match (nth, setpos) {
+                    ("", Some(nth)) => nth,
+                    (nth, None) => nth.trim_start_matches('+').parse().map_err(|_| invalid())?,
+                    _ => return Err(invalid()),
+                };
+                match nth {
+                    -1 | 1..=5 => Ok(Recurrence::Monthly { nth, weekday }),
+                    _ => Err(invalid()),
+                }
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Returns the `nth` weekday of the month, `-1` being the last, if the month has one.
+fn nth_weekday(month: NaiveDate, nth: i8, weekday: Weekday) -> Option<NaiveDate> {
+    match u8::try_from(nth) {
+        Ok(nth) => NaiveDate::from_weekday_of_month_opt(month.year(), month.month(), weekday, nth),
+        Err(_) => (4..=5).rev().find_map(|n| {
+            NaiveDate::from_weekday_of_month_opt(month.year(), month.month(), weekday, n)
+        }),
+    }
+}
+
+fn day_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+fn weekday_of(code: &str) -> Option<Weekday> {
+    [
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ]
+    .into_iter()
+    .find(|weekday| day_code(*weekday) == code)
+}
+
+fn day_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
@@ -0,0 +1,106 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use chrono::{DateTime, Offset, Utc};
+use chrono_tz::Tz;
+
+/// How the date in a zone differs from the date where the time was given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DayShift {
+    Previous,
+    Same,
+    Next,
+}
+
+impl DayShift {
+    /// Compares the local dates of the same moment in two zones.
+    fn between(origin: &DateTime<Tz>, local: &DateTime<Tz>) -> DayShift {
+        match local.date_naive().cmp(&origin.date_naive()) {
+            Ordering::Less => DayShift::Previous,
+            Ordering::Equal => DayShift::Same,
+            Ordering::Greater => DayShift::Next,
+        }
+    }
+}
+
+impl fmt::Display for DayShift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DayShift::Previous => write!(f, "previous day"),
+            DayShift::Same => Ok(()),
+            DayShift::Next => write!(f, "next day"),
+        }
+    }
+}
+
+/// One moment as it is in one zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Row {
+    pub local: DateTime<Tz>,
+    pub shift: DayShift,
+}
+
+impl Row {
+    /// The zone of the row.
+    pub fn zone(&self) -> Tz {
+        self.local.timezone()
+    }
+
+    /// The offset from UTC the zone has at the moment, in seconds.
+    pub fn offset(&self) -> i32 {
+        self.local.offset().fix().local_minus_utc()
+    }
+}
+
+impl fmt::Display for Row {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`UTC{}` **{}** {}",
+            self.local.format("%:z"),
+            self.local.format("%a %d %b %H:%M"),
+            self.zone()
+        )?;
+        if self.shift != DayShift::Same {
+            write!(f, " ({})", self.shift)?;
+        }
+        Ok(())
+    }
+}
+
+/// Shows a moment in every zone, sorted by their offset from UTC, and marks zones where it is
+/// already the next or still the previous day compared to `origin`.
+///
+/// Zones given more than once appear once.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use chrono_tz::Tz;
+/// use walzecore::tz::convert::{table, DayShift};
+///
+/// let at = Utc.with_ymd_and_hms(2026, 11, 2, 22, 0, 0).unwrap();
+/// let zones = [Tz::Asia__Kolkata, Tz::America__New_York, Tz::Europe__Berlin, Tz::Asia__Kolkata];
+/// let rows = table(at, Tz::Europe__Berlin, &zones);
+///
+/// let zones: Vec<Tz> = rows.iter().map(|row| row.zone()).collect();
+/// assert_eq!(zones, [Tz::America__New_York, Tz::Europe__Berlin, Tz::Asia__Kolkata]);
+/// assert_eq!(rows[2].shift, DayShift::Next);
+/// assert_eq!(rows[2].to_string(), "`UTC+05:30` **Tue 03 Nov 03:30** Asia/Kolkata (next day)");
+/// assert_eq!(rows[0].to_string(), "`UTC-05:00` **Mon 02 Nov 17:00** America/New_York");
+/// ```
+pub fn table(at: DateTime<Utc>, origin: Tz, zones: &[Tz]) -> Vec<Row> {
+    let origin = at.with_timezone(&origin);
+    let mut rows: Vec<Row> = zones
+        .iter()
+        .map(|zone| {
+            let local = at.with_timezone(zone);
+            let shift = DayShift::between(&origin, &local);
+            Row { local, shift }
+        })
+        .collect();
+    rows.sort_by_key(|row| (row.offset(), row.zone().name()));
+    rows.dedup_by_key(|row| row.zone());
+    rows
+}
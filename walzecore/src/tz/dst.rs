@@ -0,0 +1,153 @@
+use std::fmt;
+
+use chrono::{DateTime, Duration, LocalResult, NaiveDateTime, TimeZone};
+use chrono_tz::Tz;
+
+use crate::tz;
+use crate::tz::Result;
+
+/// How far around a nonexistent time to look for valid ones; no zone skips more than a day.
+const SEARCH_MINUTES: i64 = 24 * 60;
+
+/// Which of the two instants to take when a local time occurs twice as the clocks fall back.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Fold {
+    /// The first occurrence, still on summer time.
+    #[default]
+    Earliest,
+    /// The second occurrence, after the clocks went back.
+    Latest,
+}
+
+/// What to tell the user about a local time that did not map to one instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Warning {
+    /// The local time occurs twice, `other` is the occurrence that was not taken.
+    Ambiguous { fold: Fold, other: DateTime<Tz> },
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Warning::Ambiguous { fold, other } => {
+                let (taken, other_name) = match fold {
+                    Fold::Earliest => ("earlier", "later"),
+                    Fold::Latest => ("later", "earlier"),
+                };
+                write!(
+                    f,
+                    "this time occurs twice as the clocks go back, the {taken} one was taken; \
+                     the {other_name} one is at UTC offset {}",
+                    other.format("%:z")
+                )
+            }
+        }
+    }
+}
+
+/// A local time resolved to an instant in its zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Resolved {
+    pub at: DateTime<Tz>,
+    pub warning: Option<Warning>,
+}
+
+/// Resolves a local time in a zone to an instant, taking `fold` if it occurs twice.
+///
+/// # Errors
+///
+/// Returns an error naming the nearest valid times around it if the local time does not exist,
+/// because the clocks spring forward over it.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use chrono_tz::Europe::Berlin;
+/// use walzecore::tz::dst::{resolve, Fold};
+///
+/// let local = |month, day, hour, min| {
+///     let date = NaiveDate::from_ymd_opt(2026, month, day).unwrap();
+///     date.and_hms_opt(hour, min, 0).unwrap()
+/// };
+///
+/// // on 25 October 2026 the clocks go back from 03:00 to 02:00
+/// let fall = local(10, 25, 2, 0);
+/// let early = resolve(Berlin, fall, Fold::Earliest)?;
+/// let late = resolve(Berlin, fall, Fold::Latest)?;
+/// assert_eq!(early.at.format("%H:%M %:z").to_string(), "02:00 +02:00");
+/// assert_eq!(late.at.format("%H:%M %:z").to_string(), "02:00 +01:00");
+/// assert!(early.warning.is_some());
+///
+/// // on 29 March 2026 the clocks jump from 02:00 to 03:00
+/// let err = resolve(Berlin, local(3, 29, 2, 30), Fold::Earliest).unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "29/03/2026 02:30 does not exist in Europe/Berlin as the clocks skip it, \
+///      try 01:59 or 03:00 instead"
+/// );
+///
+/// assert!(resolve(Berlin, local(3, 30, 2, 30), Fold::Earliest)?.warning.is_none());
+/// # Ok::<(), self::walzecore::tz::Error>(())
+/// ```
+pub fn resolve<'a>(timezone: Tz, local: NaiveDateTime, fold: Fold) -> Result<'a, Resolved> {
+    match timezone.from_local_datetime(&local) {
+        LocalResult::Single(at) => Ok(Resolved { at, warning: None }),
+        LocalResult::Ambiguous(earliest, latest) => {
+            let (at, other) = match fold {
+                Fold::Earliest => (earliest, latest),
+                Fold::Latest => (latest, earliest),
+            };
+            Ok(Resolved {
+                at,
+                warning: Some(Warning::Ambiguous { fold, other }),
+            })
+        }
+        LocalResult::None => {
+            let exists = |minutes| {
+                let local = local + Duration::minutes(minutes);
+                (timezone.from_local_datetime(&local) != LocalResult::None).then_some(local)
+            };
+            let before = (1..=SEARCH_MINUTES).find_map(|minutes| exists(-minutes));
+            let after = (1..=SEARCH_MINUTES).find_map(exists);
+            Err(tz::Error::NonexistentTime {
+                timezone,
+                local,
+                before: before.unwrap_or(local),
+                after: after.unwrap_or(local),
+            })
+        }
+    }
+}
+
+/// Resolves a local time in a zone to an instant across DST changes, taking the earliest if it
+/// occurs twice and the first valid time after it if the clocks skip it.
+///
+/// Returns `None` only if no valid time follows closely.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use chrono_tz::Europe::Berlin;
+/// use walzecore::tz::dst::resolve_forward;
+///
+/// let local = |month, day, hour, min| {
+///     let date = NaiveDate::from_ymd_opt(2026, month, day).unwrap();
+///     date.and_hms_opt(hour, min, 0).unwrap()
+/// };
+///
+/// let skipped = resolve_forward(Berlin, local(3, 29, 2, 30)).unwrap();
+/// assert_eq!(skipped.format("%H:%M %:z").to_string(), "03:00 +02:00");
+/// let repeated = resolve_forward(Berlin, local(10, 25, 2, 30)).unwrap();
+/// assert_eq!(repeated.format("%H:%M %:z").to_string(), "02:30 +02:00");
+/// ```
+pub fn resolve_forward(timezone: Tz, local: NaiveDateTime) -> Option<DateTime<Tz>> {
+    match resolve(timezone, local, Fold::Earliest) {
+        Ok(resolved) => Some(resolved.at),
+        Err(tz::Error::NonexistentTime { after, .. }) => resolve(timezone, after, Fold::Earliest)
+            .ok()
+            .map(|resolved| resolved.at),
+        Err(_) => None,
+    }
+}
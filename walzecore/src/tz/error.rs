@@ -30,8 +30,28 @@ pub enum Error<'e> {
     OutofRange(#[from] chrono::OutOfRangeError),
     #[error("failed to parse timezone {0}")]
     TzParseFail(&'e str),
+    #[error("\"{0}\" could mean {}, give the name of one", .1.join(", "))]
+    AmbiguousTimezone(&'e str, Vec<String>),
     #[error("could not parse time {0}")]
     TimeParseFail(&'e str),
     #[error("could not parse date {0}")]
     DateParseError(&'e str),
+    #[error(
+        "could not understand the window \"{0}\", try \"fri 18:00-23:00\" or \"mon-thu 7pm-11pm\""
+    )]
+    WindowParseFail(&'e str),
+    #[error("could not understand \"{0}\", try \"tomorrow 7pm\", \"next friday 20:30\" or \"in 3 hours\"")]
+    NaturalParseFail(&'e str),
+    #[error(
+        "{} does not exist in {timezone} as the clocks skip it, try {} or {} instead",
+        local.format("%d/%m/%Y %H:%M"),
+        before.format("%H:%M"),
+        after.format("%H:%M")
+    )]
+    NonexistentTime {
+        timezone: chrono_tz::Tz,
+        local: chrono::NaiveDateTime,
+        before: chrono::NaiveDateTime,
+        after: chrono::NaiveDateTime,
+    },
 }
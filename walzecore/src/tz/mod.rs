@@ -1,5 +1,10 @@
+pub mod convert;
+pub mod dst;
 pub mod error;
+pub mod natural;
 pub mod stamp;
+pub mod window;
+pub mod zone;
 
 pub use error::Error;
 pub use error::Result;
@@ -13,7 +18,8 @@ lazy_static! {
     static ref TIME_REGEX: Regex = Regex::new(
         r"^(([1-9]|0[0-9]|1[0-9]|2[0-3]){0,1})h(([0-9]|0[0-9]|1[0-9]|2[0-9]|3[0-9]|4[0-9]|5[0-9]){0,1})m(([0-9]|0[0-9]|1[0-9]|2[0-9]|3[0-9]|4[0-9]|5[0-9]){0,1})s$"
     ).unwrap();
-    static ref DATE_REGEX: Regex = Regex::new(r"([0-9]{1,2})/([0-9]{1,2})/([0-9]{2,4})\b").unwrap();
+    /// Matches `DD/MM/YYYY` and `DD/MM/YY`
+    static ref DATE_REGEX: Regex = Regex::new(r"^([0-9]{1,2})/([0-9]{1,2})/([0-9]{2}|[0-9]{4})$").unwrap();
 }
 
 fn extract_group<'g>(captures: &'g Captures<'g>, idx: usize) -> &'g str {
@@ -31,11 +37,30 @@ pub fn hms(captures: &Captures) -> (u32, u32, u32) {
 }
 
 pub fn dmy(captures: &Captures) -> (u32, u32, i32) {
-    let grp = extract_group(captures, 3).parse().unwrap_or_default();
-    let year = if grp < 2000 { grp + 2000 } else { grp };
+    let year = year(extract_group(captures, 3)).unwrap_or_default();
     (
         extract_group(captures, 1).parse().unwrap_or_default(),
         extract_group(captures, 2).parse().unwrap_or_default(),
         year,
     )
 }
+
+/// Reads a two-digit year as one of the 2000s and a four-digit year as it is.
+///
+/// # Examples
+///
+/// ```
+/// use walzecore::tz::year;
+///
+/// assert_eq!(year("26"), Some(2026));
+/// assert_eq!(year("1999"), Some(1999));
+/// assert_eq!(year("999"), None);
+/// ```
+pub fn year(digits: &str) -> Option<i32> {
+    let year: i32 = digits.parse().ok()?;
+    match digits.len() {
+        2 => Some(2000 + year),
+        4 => Some(year),
+        _ => None,
+    }
+}
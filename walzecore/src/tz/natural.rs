@@ -0,0 +1,248 @@
+use std::fmt;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use chrono_tz::Tz;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::tz;
+use crate::tz::Result;
+
+lazy_static! {
+    /// Matches `7pm`, `7:30pm`, `19:30` and `19:30:15`
+    static ref CLOCK_REGEX: Regex = Regex::new(
+        r"^([0-9]{1,2})(?::([0-5][0-9]))?(?::([0-5][0-9]))?\s*(am|pm|a\.m\.|p\.m\.)?$"
+    )
+    .unwrap();
+    /// Matches an amount and unit of a relative phrase, `3 hours` or `2h`
+    static ref SPAN_REGEX: Regex =
+        Regex::new(r"([0-9]+|an?)\s*(weeks?|w|days?|d|hours?|hrs?|h|minutes?|mins?|m)\b").unwrap();
+}
+
+/// Time of day used for "tonight" when no time is given.
+const TONIGHT: u32 = 20;
+
+/// How an input was read: the local date and time it names in the target zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interpretation {
+    pub local: NaiveDateTime,
+}
+
+impl fmt::Display for Interpretation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.local.format("%a %d %b %Y %H:%M"))
+    }
+}
+
+/// Parses a time of day, `19:30`, `7:30pm`, `7pm`, `noon` or `midnight`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveTime;
+/// use walzecore::tz::natural::parse_clock;
+///
+/// let at = |h, m| NaiveTime::from_hms_opt(h, m, 0);
+/// assert_eq!(parse_clock("19:30"), at(19, 30));
+/// assert_eq!(parse_clock("7:30pm"), at(19, 30));
+/// assert_eq!(parse_clock("7 PM"), at(19, 0));
+/// assert_eq!(parse_clock("12am"), at(0, 0));
+/// assert_eq!(parse_clock("noon"), at(12, 0));
+/// assert_eq!(parse_clock("25:00"), None);
+/// assert_eq!(parse_clock("13pm"), None);
+/// ```
+pub fn parse_clock(input: &str) -> Option<NaiveTime> {
+    let input = input.trim().to_lowercase();
+    match input.as_str() {
+        "noon" | "midday" => return NaiveTime::from_hms_opt(12, 0, 0),
+        "midnight" => return NaiveTime::from_hms_opt(0, 0, 0),
+        _ => {}
+    }
+
+    let caps = CLOCK_REGEX.captures(&input)?;
+    let number = |idx| caps.get(idx).map_or(Some(0), |m| m.as_str().parse().ok());
+    let (hour, min, sec): (u32, u32, u32) = (number(1)?, number(2)?, number(3)?);
+    // a bare number is not a time, it could be a day or an amount
+    if caps.get(2).is_none() && caps.get(4).is_none() {
+        return None;
+    }
+
+    let hour = match caps.get(4).map(|m| m.as_str().starts_with('p')) {
+        Some(_) if !(1..=12).contains(&hour) => return None,
+        Some(true) => hour % 12 + 12,
+        Some(false) => hour % 12,
+        None => hour,
+    };
+    NaiveTime::from_hms_opt(hour, min, sec)
+}
+
+/// Parses a date and time written in plain language, resolving relative phrases against `now`
+/// in the target zone.
+///
+/// Accepted are `now`, relative spans such as `in 3 hours` or `in 1 day 2h`, a day (`today`,
+/// `tonight`, `tomorrow`, a weekday or `next <weekday>`) with a time such as `tomorrow 7pm` or
+/// `next friday at 20:30`, a bare time meaning its next occurrence, and ISO 8601 date times
+/// like `2026-11-02T19:00`.
+///
+/// # Errors
+///
+/// Returns an error if the input is not understood.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::TimeZone;
+/// use chrono_tz::Europe::Berlin;
+/// use walzecore::tz::natural::parse_natural;
+///
+/// // Sunday 18 October 2026, 14:00 in Berlin
+/// let now = Berlin.with_ymd_and_hms(2026, 10, 18, 14, 0, 0).unwrap();
+/// let read = |input| parse_natural(input, &now).map(|at| at.to_string());
+///
+/// assert_eq!(read("tomorrow 7pm")?, "Mon 19 Oct 2026 19:00");
+/// assert_eq!(read("next friday 20:30")?, "Fri 23 Oct 2026 20:30");
+/// assert_eq!(read("sunday at 9am")?, "Sun 25 Oct 2026 09:00");
+/// assert_eq!(read("in 3 hours")?, "Sun 18 Oct 2026 17:00");
+/// assert_eq!(read("in 1 day 2h")?, "Mon 19 Oct 2026 16:00");
+/// assert_eq!(read("tonight")?, "Sun 18 Oct 2026 20:00");
+/// assert_eq!(read("11am")?, "Mon 19 Oct 2026 11:00");
+/// assert_eq!(read("2026-11-02T19:00")?, "Mon 02 Nov 2026 19:00");
+/// assert!(read("whenever").is_err());
+/// # Ok::<(), self::walzecore::tz::Error>(())
+/// ```
+pub fn parse_natural<'a>(input: &'a str, now: &DateTime<Tz>) -> Result<'a, Interpretation> {
+    let fail = || tz::Error::NaturalParseFail(input);
+    let text = input.trim().to_lowercase().replace(',', " ");
+    let local_now = now.naive_local();
+
+    if text == "now" {
+        return Ok(Interpretation { local: local_now });
+    }
+    if let Some(local) = parse_iso(input.trim(), now) {
+        return Ok(Interpretation { local });
+    }
+    if let Some(span) = text.strip_prefix("in ") {
+        let local = (*now + parse_span(span).ok_or_else(fail)?).naive_local();
+        return Ok(Interpretation { local });
+    }
+
+    let mut date = None;
+    let mut next = false;
+    let mut clock = Vec::new();
+    for word in text.split_whitespace() {
+        match word {
+            "at" | "on" | "this" => {}
+            "next" => next = true,
+            "today" => date = Some(local_now.date()),
+            "tonight" => {
+                date = Some(local_now.date());
+                if clock.is_empty() {
+                    clock.push(format!("{TONIGHT}:00"));
+                }
+            }
+            "tomorrow" => date = local_now.date().succ_opt(),
+            word => match word.parse::<Weekday>() {
+                Ok(weekday) => date = Some(upcoming(local_now.date(), weekday, next)),
+                Err(_) => clock.push(word.to_string()),
+            },
+        }
+    }
+
+    // tonight only provides a time when no other time is given
+    if clock.len() > 1 && text.contains("tonight") {
+        clock.remove(0);
+    }
+    let time = parse_clock(&clock.join("")).ok_or_else(fail)?;
+    let local = match date {
+        Some(date) => date.and_time(time),
+        None => {
+            // a bare time means its next occurrence
+            let today = local_now.date().and_time(time);
+            if today > local_now {
+                today
+            } else {
+                today + Duration::days(1)
+            }
+        }
+    };
+
+    // a plain weekday that is today but already past means next week
+    let local = match (date, next) {
+        (Some(date), false) if date == local_now.date() && local <= local_now => {
+            if text
+                .split_whitespace()
+                .any(|word| word.parse::<Weekday>().is_ok())
+            {
+                local + Duration::days(7)
+            } else {
+                local
+            }
+        }
+        _ => local,
+    };
+
+    Ok(Interpretation { local })
+}
+
+/// Parses an ISO 8601 date time, converting it into the zone if it carries an offset.
+fn parse_iso(input: &str, now: &DateTime<Tz>) -> Option<NaiveDateTime> {
+    if let Ok(at) = DateTime::parse_from_rfc3339(input) {
+        return Some(at.with_timezone(&now.timezone()).naive_local());
+    }
+    [
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+    ]
+    .iter()
+    .find_map(|format| NaiveDateTime::parse_from_str(input, format).ok())
+}
+
+/// Sums the amounts of a relative span like `3 hours` or `1 day 2h`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Duration;
+/// use walzecore::tz::natural::parse_span;
+///
+/// assert_eq!(parse_span("2h 30m"), Some(Duration::minutes(150)));
+/// assert_eq!(parse_span("an hour 15 minutes"), Some(Duration::minutes(75)));
+/// assert_eq!(parse_span("a while"), None);
+/// ```
+pub fn parse_span(span: &str) -> Option<Duration> {
+    let mut total = Duration::zero();
+    for caps in SPAN_REGEX.captures_iter(span) {
+        let amount: i64 = match &caps[1] {
+            "a" | "an" => 1,
+            amount => amount.parse().ok()?,
+        };
+        let unit = match caps[2].chars().next()? {
+            'w' => Duration::try_weeks(amount)?,
+            'd' => Duration::try_days(amount)?,
+            'h' => Duration::try_hours(amount)?,
+            _ => Duration::try_minutes(amount)?,
+        };
+        total = total.checked_add(&unit)?;
+    }
+
+    // everything but whitespace and "and" has to belong to a span
+    let rest = span
+        .replace("and", "")
+        .split_whitespace()
+        .collect::<String>()
+        .len();
+    let spans: usize = SPAN_REGEX
+        .find_iter(span)
+        .map(|m| m.as_str().split_whitespace().collect::<String>().len())
+        .sum();
+    (spans > 0 && rest == spans).then_some(total)
+}
+
+/// Returns the next date with the weekday, today included unless `next` is set.
+fn upcoming(today: NaiveDate, weekday: Weekday, next: bool) -> NaiveDate {
+    let ahead = (7 + weekday.num_days_from_monday() - today.weekday().num_days_from_monday()) % 7;
+    let ahead = if ahead == 0 && next { 7 } else { ahead };
+    today + Duration::days(i64::from(ahead))
+}
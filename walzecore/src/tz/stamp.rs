@@ -1,25 +1,180 @@
-use regex::Captures;
+use chrono::{DateTime, Datelike, Duration, Month, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use chrono_tz::Tz;
+use lazy_static::lazy_static;
+use regex::Regex;
 
 use crate::tz;
+use crate::tz::natural::{parse_clock, parse_natural};
 use crate::tz::Result;
 use crate::tz::DATE_REGEX;
 use crate::tz::TIME_REGEX;
 
+lazy_static! {
+    /// Matches `3 Nov 2026`, `3rd November` and `Nov 3, 2026`
+    static ref MONTH_NAME_REGEX: Regex = Regex::new(
+        r"^(?:(?P<day>[0-9]{1,2})(?:st|nd|rd|th)?\s+(?P<month>[a-z]+)\.?|(?P<month2>[a-z]+)\.?\s+(?P<day2>[0-9]{1,2})(?:st|nd|rd|th)?),?(?:\s+(?P<year>[0-9]+))?$"
+    )
+    .unwrap();
+    /// Matches `3/11`, a day and month without a year
+    static ref DAY_MONTH_REGEX: Regex = Regex::new(r"^([0-9]{1,2})/([0-9]{1,2})$").unwrap();
+}
+
+/// Parses the timezone, date and time given to `/tz`.
+///
+/// Dates without a year mean their next occurrence in the timezone.
+///
+/// # Errors
+///
+/// Returns an error naming the input that could not be parsed.
 pub fn parse_tz_date_time<'a>(
     timezone: &'a str,
     dmy: &'a str,
     hms: &'a str,
-) -> Result<'a, (chrono_tz::Tz, Captures<'a>, Captures<'a>)> {
-    let tz = match timezone.parse::<chrono_tz::Tz>() {
-        Ok(tz) => tz,
-        Err(_) => return Err(tz::Error::TzParseFail(timezone)),
-    };
-    let Some(date) = DATE_REGEX.captures(dmy) else {
+) -> Result<'a, (chrono_tz::Tz, NaiveDate, NaiveTime)> {
+    let now = Utc::now();
+    let tz = tz::zone::resolve(timezone, now)?;
+    let today = now.with_timezone(&tz).date_naive();
+    let Some(date) = parse_date(dmy, today) else {
         return Err(tz::Error::DateParseError(dmy));
     };
-    let Some(time) = TIME_REGEX.captures(hms) else {
+    let Some(time) = parse_time(hms) else {
         return Err(tz::Error::TimeParseFail(hms));
     };
 
     Ok((tz, date, time))
 }
+
+/// Parses a moment in the zone of `now`, written in plain language like `tomorrow 7pm` or as a
+/// date and a time in either order, `3 Nov 19:30` or `7pm 2026-11-02`.
+///
+/// A time without a date means its next occurrence.
+///
+/// # Errors
+///
+/// Returns an error if the input is not understood.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::TimeZone;
+/// use chrono_tz::Europe::Berlin;
+/// use walzecore::tz::stamp::parse_moment;
+///
+/// let now = Berlin.with_ymd_and_hms(2026, 10, 18, 14, 0, 0).unwrap();
+/// let read = |input| parse_moment(input, &now).map(|at| at.format("%d/%m/%Y %H:%M").to_string());
+///
+/// assert_eq!(read("tomorrow 7pm")?, "19/10/2026 19:00");
+/// assert_eq!(read("3 Nov 2026 19:30")?, "03/11/2026 19:30");
+/// assert_eq!(read("7:30pm 2/11/26")?, "02/11/2026 19:30");
+/// assert_eq!(read("13h00m00s")?, "19/10/2026 13:00");
+/// assert!(read("3 Nov").is_err());
+/// # Ok::<(), self::walzecore::tz::Error>(())
+/// ```
+pub fn parse_moment<'a>(input: &'a str, now: &DateTime<Tz>) -> Result<'a, NaiveDateTime> {
+    if let Ok(read) = parse_natural(input, now) {
+        return Ok(read.local);
+    }
+
+    let local_now = now.naive_local();
+    if let Some(time) = parse_time(input) {
+        let today = local_now.date().and_time(time);
+        return Ok(if today > local_now {
+            today
+        } else {
+            today + Duration::days(1)
+        });
+    }
+
+    let words: Vec<&str> = input.split_whitespace().collect();
+    (1..words.len())
+        .find_map(|split| {
+            let (first, second) = (words[..split].join(" "), words[split..].join(" "));
+            let at = |date: &str, time: &str| {
+                Some(parse_date(date, local_now.date())?.and_time(parse_time(time)?))
+            };
+            at(&first, &second).or_else(|| at(&second, &first))
+        })
+        .ok_or(tz::Error::NaturalParseFail(input))
+}
+
+/// Parses a time of day, `19:30`, `7:30pm`, `7pm` or `19h30m00s`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveTime;
+/// use walzecore::tz::stamp::parse_time;
+///
+/// let at = |h, m, s| NaiveTime::from_hms_opt(h, m, s);
+/// assert_eq!(parse_time("19h30m15s"), at(19, 30, 15));
+/// assert_eq!(parse_time("7h"), None);
+/// assert_eq!(parse_time("7:30pm"), at(19, 30, 0));
+/// assert_eq!(parse_time("7pm"), at(19, 0, 0));
+/// assert_eq!(parse_time("19:30"), at(19, 30, 0));
+/// ```
+pub fn parse_time(input: &str) -> Option<NaiveTime> {
+    let input = input.trim();
+    if let Some(time) = TIME_REGEX.captures(input) {
+        let (hour, min, sec) = tz::hms(&time);
+        return NaiveTime::from_hms_opt(hour, min, sec);
+    }
+    parse_clock(input)
+}
+
+/// Parses a date, `2026-11-02`, `2/11/2026`, `2/11/26`, `3 Nov 2026` or `Nov 3, 2026`.
+///
+/// Dates without a year, `3 Nov` or `3/11`, mean their next occurrence from `today` on.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use walzecore::tz::stamp::parse_date;
+///
+/// let today = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+/// assert_eq!(parse_date("2026-11-02", today), date(2026, 11, 2));
+/// assert_eq!(parse_date("2/11/2026", today), date(2026, 11, 2));
+/// assert_eq!(parse_date("2/11/99", today), date(2099, 11, 2));
+/// assert_eq!(parse_date("3 Nov 2026", today), date(2026, 11, 3));
+/// assert_eq!(parse_date("November 3rd, 2026", today), date(2026, 11, 3));
+/// assert_eq!(parse_date("3 nov", today), date(2026, 11, 3));
+/// assert_eq!(parse_date("1 Jan", today), date(2027, 1, 1));
+/// assert_eq!(parse_date("18/10", today), date(2026, 10, 18));
+/// assert_eq!(parse_date("2/11/026", today), None);
+/// assert_eq!(parse_date("31/2/2026", today), None);
+/// ```
+pub fn parse_date(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let input = input.trim().to_lowercase();
+
+    if let Ok(date) = NaiveDate::parse_from_str(&input, "%Y-%m-%d") {
+        return Some(date);
+    }
+    if let Some(date) = DATE_REGEX.captures(&input) {
+        let (day, month, year) = tz::dmy(&date);
+        return NaiveDate::from_ymd_opt(year, month, day);
+    }
+    if let Some(caps) = DAY_MONTH_REGEX.captures(&input) {
+        let day = caps[1].parse().ok()?;
+        let month = caps[2].parse().ok()?;
+        return next_occurrence(today, month, day);
+    }
+
+    let caps = MONTH_NAME_REGEX.captures(&input)?;
+    let day = caps.name("day").or_else(|| caps.name("day2"))?;
+    let month = caps.name("month").or_else(|| caps.name("month2"))?;
+    let day = day.as_str().parse().ok()?;
+    let month = month.as_str().parse::<Month>().ok()?.number_from_month();
+    match caps.name("year") {
+        Some(year) => NaiveDate::from_ymd_opt(tz::year(year.as_str())?, month, day),
+        None => next_occurrence(today, month, day),
+    }
+}
+
+/// Returns the next date on or after `today` with the given month and day.
+fn next_occurrence(today: NaiveDate, month: u32, day: u32) -> Option<NaiveDate> {
+    // february 29th only comes around every few years
+    (0..=8)
+        .filter_map(|ahead| NaiveDate::from_ymd_opt(today.year() + ahead, month, day))
+        .find(|date| *date >= today)
+}
@@ -0,0 +1,345 @@
+use std::fmt;
+
+use chrono::{DateTime, Datelike, Duration, NaiveTime, Utc, Weekday};
+use chrono_tz::Tz;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::tz::dst;
+use crate::tz::natural::parse_clock;
+use crate::tz::{self, Result};
+
+lazy_static! {
+    /// Matches `fri 18:00-23:00` and `mon-fri 7pm-11pm`
+    static ref WINDOW_REGEX: Regex =
+        Regex::new(r"^([a-z]+)(?:\s*-\s*([a-z]+))?\s+(.+?)\s*-\s*(.+)$").unwrap();
+}
+
+/// A weekly window of time in which a member is available, in the member's own zone.
+///
+/// A window ending at or before its start runs past midnight into the next day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Window {
+    pub weekday: Weekday,
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl Window {
+    /// Parses windows separated by commas, each a weekday or a range of weekdays followed by
+    /// the times they start and end, `mon-fri 19:00-23:00, sat 10pm-2am`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the window that is not understood.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use walzecore::tz::window::Window;
+    ///
+    /// let windows = Window::parse_list("mon-fri 19:00-23:00, sat 10pm-2am")?;
+    /// assert_eq!(windows.len(), 6);
+    /// assert_eq!(windows[0].to_string(), "Mon 19:00-23:00");
+    /// assert_eq!(windows[5].to_string(), "Sat 22:00-02:00");
+    ///
+    /// // ranges can wrap around the week
+    /// assert_eq!(Window::parse_list("sat-mon noon-6pm")?.len(), 3);
+    /// assert!(Window::parse_list("fri evening").is_err());
+    /// # Ok::<(), self::walzecore::tz::Error>(())
+    /// ```
+    pub fn parse_list<'a>(input: &'a str) -> Result<'a, Vec<Window>> {
+        let mut windows = Vec::new();
+        for part in input
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+        {
+            let text = part.to_lowercase();
+            let fail = || tz::Error::WindowParseFail(part);
+            let caps = WINDOW_REGEX.captures(&text).ok_or_else(fail)?;
+            let first: Weekday = caps[1].parse().map_err(|_| fail())?;
+            let last: Weekday = match caps.get(2) {
+                Some(last) => last.as_str().parse().map_err(|_| fail())?,
+                None => first,
+            };
+            let start = parse_clock(&caps[3]).ok_or_else(fail)?;
+            let end = parse_clock(&caps[4]).ok_or_else(fail)?;
+
+            let mut weekday = first;
+            loop {
+                windows.push(Window {
+                    weekday,
+                    start,
+                    end,
+                });
+                if weekday == last {
+                    break;
+                }
+                weekday = weekday.succ();
+            }
+        }
+        Ok(windows)
+    }
+
+    /// Returns how long the window is.
+    pub fn length(&self) -> Duration {
+        let length = self.end - self.start;
+        if length > Duration::zero() {
+            length
+        } else {
+            length + Duration::days(1)
+        }
+    }
+
+    /// Returns the times the window takes place in `zone` between `from` and `until`, cut
+    /// to that range.
+    ///
+    /// Across a DST change the window keeps its local times, so it can be an hour longer or
+    /// shorter than usual.
+    pub fn occurrences(
+        &self,
+        zone: Tz,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Vec<Interval> {
+        let first = from.with_timezone(&zone).date_naive() - Duration::days(1);
+        let last = until.with_timezone(&zone).date_naive();
+        first
+            .iter_days()
+            .take_while(|date| *date <= last)
+            .filter(|date| date.weekday() == self.weekday)
+            .filter_map(|date| {
+                let local = date.and_time(self.start);
+                let start = dst::resolve_forward(zone, local)?.with_timezone(&Utc);
+                let end = dst::resolve_forward(zone, local + self.length())?.with_timezone(&Utc);
+                Interval::new(start.max(from), end.min(until))
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Window {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}-{}",
+            self.weekday,
+            self.start.format("%H:%M"),
+            self.end.format("%H:%M")
+        )
+    }
+}
+
+/// A stretch of time between two instants, the end not included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl Interval {
+    /// Creates an interval, or `None` if it would end before it starts or be empty.
+    pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> Option<Interval> {
+        (start < end).then_some(Interval { start, end })
+    }
+
+    /// Returns how long the interval is.
+    pub fn length(&self) -> Duration {
+        self.end - self.start
+    }
+}
+
+/// Returns the times a member with the weekly windows in `zone` is available between `from`
+/// and `until`, merged and sorted.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use chrono_tz::Europe::Berlin;
+/// use walzecore::tz::window::{available, Window};
+///
+/// // Monday 2 November 2026, Berlin is an hour ahead of UTC
+/// let from = Utc.with_ymd_and_hms(2026, 11, 2, 0, 0, 0).unwrap();
+/// let until = from + chrono::Duration::weeks(1);
+/// let windows = Window::parse_list("fri 19:00-23:00, fri 22:00-1am")?;
+///
+/// let free = available(&windows, Berlin, from, until);
+/// assert_eq!(free.len(), 1);
+/// assert_eq!(free[0].start.to_rfc3339(), "2026-11-06T18:00:00+00:00");
+/// assert_eq!(free[0].end.to_rfc3339(), "2026-11-07T00:00:00+00:00");
+/// # Ok::<(), self::walzecore::tz::Error>(())
+/// ```
+pub fn available(
+    windows: &[Window],
+    zone: Tz,
+    from: DateTime<Utc>,
+    until: DateTime<Utc>,
+) -> Vec<Interval> {
+    merge(
+        windows
+            .iter()
+            .flat_map(|window| window.occurrences(zone, from, until))
+            .collect(),
+    )
+}
+
+/// Sorts intervals and joins those that overlap or touch.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use walzecore::tz::window::{merge, Interval};
+///
+/// let at = |hour| Utc.with_ymd_and_hms(2026, 11, 6, hour, 0, 0).unwrap();
+/// let span = |start, end| Interval::new(at(start), at(end)).unwrap();
+///
+/// let merged = merge(vec![span(18, 20), span(8, 10), span(19, 22), span(10, 11)]);
+/// assert_eq!(merged, [span(8, 11), span(18, 22)]);
+/// ```
+pub fn merge(mut intervals: Vec<Interval>) -> Vec<Interval> {
+    intervals.sort_unstable();
+    let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+    for interval in intervals {
+        match merged.last_mut() {
+            Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+            _ => merged.push(interval),
+        }
+    }
+    merged
+}
+
+/// Returns the times that are in both lists, which have to be merged.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use walzecore::tz::window::{intersect, Interval};
+///
+/// let at = |hour| Utc.with_ymd_and_hms(2026, 11, 6, hour, 0, 0).unwrap();
+/// let span = |start, end| Interval::new(at(start), at(end)).unwrap();
+///
+/// let ours = [span(8, 12), span(17, 23)];
+/// let theirs = [span(10, 18), span(20, 21)];
+/// assert_eq!(intersect(&ours, &theirs), [span(10, 12), span(17, 18), span(20, 21)]);
+/// assert!(intersect(&ours, &[]).is_empty());
+/// ```
+pub fn intersect(ours: &[Interval], theirs: &[Interval]) -> Vec<Interval> {
+    let (mut i, mut j) = (0, 0);
+    let mut common = Vec::new();
+    while let (Some(a), Some(b)) = (ours.get(i), theirs.get(j)) {
+        if let Some(both) = Interval::new(a.start.max(b.start), a.end.min(b.end)) {
+            common.push(both);
+        }
+        // whichever ends first cannot overlap anything later in the other list
+        if a.end < b.end {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    common
+}
+
+/// Returns the times everyone in the party is available, lasting at least `min`, best first:
+/// the longest slots, then the earliest.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use chrono_tz::{America::New_York, Europe::Berlin};
+/// use walzecore::tz::window::{available, overlap, Window};
+///
+/// let from = Utc.with_ymd_and_hms(2026, 11, 2, 0, 0, 0).unwrap();
+/// let until = from + Duration::weeks(1);
+/// let berlin = Window::parse_list("mon-fri 19:00-23:00, sat 14:00-22:00")?;
+/// let new_york = Window::parse_list("fri 12:00-18:00, sat 9am-11am, sat 3pm-5pm")?;
+/// let party = [
+///     available(&berlin, Berlin, from, until),
+///     available(&new_york, New_York, from, until),
+/// ];
+///
+/// let slots = overlap(&party, Duration::hours(2));
+/// let slots: Vec<String> = slots
+///     .iter()
+///     .map(|slot| format!("{} {}", slot.start.format("%a %H:%M"), slot.length().num_minutes()))
+///     .collect();
+/// assert_eq!(slots, ["Fri 18:00 240", "Sat 14:00 120"]);
+///
+/// assert!(overlap(&party, Duration::hours(5)).is_empty());
+/// assert!(overlap(&[], Duration::hours(1)).is_empty());
+/// # Ok::<(), self::walzecore::tz::Error>(())
+/// ```
+pub fn overlap(party: &[Vec<Interval>], min: Duration) -> Vec<Interval> {
+    let Some((first, rest)) = party.split_first() else {
+        return Vec::new();
+    };
+    let mut slots = rest
+        .iter()
+        .fold(first.clone(), |common, free| intersect(&common, free));
+    slots.retain(|slot| slot.length() >= min);
+    slots.sort_by_key(|slot| (std::cmp::Reverse(slot.length()), slot.start));
+    slots
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use chrono_tz::Europe::Berlin;
+
+    use super::*;
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 11, 6, hour, 0, 0).unwrap()
+    }
+
+    fn span(start: u32, end: u32) -> Interval {
+        Interval::new(at(start), at(end)).unwrap()
+    }
+
+    #[test]
+    fn touching_windows_do_not_overlap() {
+        let party = [vec![span(18, 20)], vec![span(20, 22)]];
+        assert!(overlap(&party, Duration::minutes(1)).is_empty());
+    }
+
+    #[test]
+    fn identical_windows_overlap_completely() {
+        let party = [vec![span(18, 22)], vec![span(18, 22)], vec![span(18, 22)]];
+        assert_eq!(overlap(&party, Duration::hours(4)), [span(18, 22)]);
+    }
+
+    #[test]
+    fn a_member_without_windows_leaves_no_overlap() {
+        let party = [vec![span(18, 22)], Vec::new()];
+        assert!(overlap(&party, Duration::minutes(1)).is_empty());
+    }
+
+    #[test]
+    fn slots_of_equal_length_are_ranked_earliest_first() {
+        let party = [
+            vec![span(8, 10), span(12, 14), span(18, 20)],
+            vec![span(6, 22)],
+        ];
+        assert_eq!(
+            overlap(&party, Duration::hours(2)),
+            [span(8, 10), span(12, 14), span(18, 20)]
+        );
+    }
+
+    #[test]
+    fn a_window_over_the_clocks_going_back_is_an_hour_longer() {
+        // Berlin leaves summer time at 03:00 on Sunday 25 October
+        let window = Window::parse_list("sun 0:00-6:00").unwrap()[0];
+        let from = Utc.with_ymd_and_hms(2026, 10, 24, 0, 0, 0).unwrap();
+        let until = from + Duration::days(2);
+        let occurrences = window.occurrences(Berlin, from, until);
+        assert_eq!(occurrences.len(), 1);
+        assert_eq!(occurrences[0].length(), Duration::hours(7));
+    }
+}
@@ -0,0 +1,328 @@
+use std::cmp::Reverse;
+
+use chrono::{DateTime, Duration, FixedOffset, Offset, TimeZone, Utc};
+use chrono_tz::{OffsetName, Tz, TZ_VARIANTS};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::tz;
+use crate::tz::Result;
+
+lazy_static! {
+    /// Matches a raw offset from UTC, `UTC+5:30`, `GMT-3` or `+0200`
+    static ref OFFSET_REGEX: Regex =
+        Regex::new(r"^(?:utc|gmt)?\s*([+-])\s*([0-9]{1,2})(?::?([0-5][0-9]))?$").unwrap();
+}
+
+/// Discord shows at most 25 autocomplete choices.
+pub const MAX_SUGGESTIONS: usize = 25;
+
+/// Zones meant by common abbreviations that several zones share.
+const PREFERRED: &[(&str, Tz)] = &[
+    ("EST", Tz::America__New_York),
+    ("EDT", Tz::America__New_York),
+    ("CST", Tz::America__Chicago),
+    ("CDT", Tz::America__Chicago),
+    ("MST", Tz::America__Denver),
+    ("MDT", Tz::America__Denver),
+    ("PST", Tz::America__Los_Angeles),
+    ("PDT", Tz::America__Los_Angeles),
+    ("AKST", Tz::America__Anchorage),
+    ("AKDT", Tz::America__Anchorage),
+    ("HST", Tz::Pacific__Honolulu),
+    ("BST", Tz::Europe__London),
+    ("WET", Tz::Europe__Lisbon),
+    ("WEST", Tz::Europe__Lisbon),
+    ("CET", Tz::Europe__Berlin),
+    ("CEST", Tz::Europe__Berlin),
+    ("EET", Tz::Europe__Athens),
+    ("EEST", Tz::Europe__Athens),
+    ("MSK", Tz::Europe__Moscow),
+    ("JST", Tz::Asia__Tokyo),
+    ("KST", Tz::Asia__Seoul),
+    ("AEST", Tz::Australia__Sydney),
+    ("AEDT", Tz::Australia__Sydney),
+    ("NZST", Tz::Pacific__Auckland),
+    ("NZDT", Tz::Pacific__Auckland),
+];
+
+/// Zones preferred for offsets that are not whole hours, where the first match would be an
+/// outdated alias like `Asia/Calcutta`.
+const FRACTIONAL: &[Tz] = &[
+    Tz::Asia__Kolkata,
+    Tz::Asia__Kathmandu,
+    Tz::Asia__Kabul,
+    Tz::Asia__Yangon,
+    Tz::Australia__Darwin,
+    Tz::Australia__Eucla,
+    Tz::Pacific__Marquesas,
+];
+
+/// Resolves a timezone given by its IANA name, an abbreviation such as `EST` or `CEST`, an
+/// offset such as `UTC+5:30` or the name of a city in it, such as `Berlin` or `new york`.
+///
+/// Abbreviations are read as they are in use around `now`. An offset resolves to a zone that
+/// keeps it all year.
+///
+/// # Errors
+///
+/// Returns an error if no zone matches, or if an abbreviation is shared by zones that are
+/// apart, naming the zones it could mean.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use chrono_tz::Tz;
+/// use walzecore::tz::zone::resolve;
+///
+/// let now = Utc.with_ymd_and_hms(2026, 10, 18, 12, 0, 0).unwrap();
+/// assert_eq!(resolve("europe/berlin", now)?, Tz::Europe__Berlin);
+/// assert_eq!(resolve("Berlin", now)?, Tz::Europe__Berlin);
+/// assert_eq!(resolve("new york", now)?, Tz::America__New_York);
+/// assert_eq!(resolve("CEST", now)?, Tz::Europe__Berlin);
+/// assert_eq!(resolve("est", now)?, Tz::America__New_York);
+/// assert_eq!(resolve("UTC+5:30", now)?, Tz::Asia__Kolkata);
+/// assert_eq!(resolve("GMT-3", now)?, Tz::Etc__GMTPlus3);
+/// assert_eq!(resolve("utc", now)?, Tz::UTC);
+/// assert_eq!(
+///     resolve("IST", now).unwrap_err().to_string(),
+///     "\"IST\" could mean Europe/Dublin (+01:00), Asia/Kolkata (+05:30), give the name of one"
+/// );
+/// assert!(resolve("Atlantis", now).is_err());
+/// # Ok::<(), self::walzecore::tz::Error>(())
+/// ```
+pub fn resolve(input: &str, now: DateTime<Utc>) -> Result<'_, Tz> {
+    let query = normalize(input);
+    // legacy zones like EST or CET are named like the abbreviation but are not what is meant
+    let abbreviation = query.to_uppercase();
+    if let Some((_, tz)) = PREFERRED.iter().find(|(abbr, _)| *abbr == abbreviation) {
+        return Ok(*tz);
+    }
+    if let Some(tz) = TZ_VARIANTS.iter().find(|tz| normalize(tz.name()) == query) {
+        return Ok(*tz);
+    }
+    if matches!(query.as_str(), "utc" | "gmt" | "z") {
+        return Ok(Tz::UTC);
+    }
+    if let Some(offset) = parse_offset(&query) {
+        return fixed_zone(offset, now).ok_or(tz::Error::TzParseFail(input));
+    }
+
+    let candidates = abbreviated(&abbreviation, now);
+    if let Some((first, offset)) = candidates.first() {
+        if candidates.iter().all(|(_, other)| other == offset) {
+            return Ok(*first);
+        }
+        // one zone for each offset is enough to tell them apart
+        let mut distinct = candidates.clone();
+        distinct.sort_by_key(|(tz, offset)| (offset.local_minus_utc(), !is_known(*tz)));
+        distinct.dedup_by_key(|(_, offset)| *offset);
+        let zones = distinct
+            .iter()
+            .map(|(tz, offset)| format!("{} ({offset})", tz.name()))
+            .collect();
+        return Err(tz::Error::AmbiguousTimezone(input, zones));
+    }
+
+    TZ_VARIANTS
+        .iter()
+        .find(|tz| city(tz.name()) == query)
+        .copied()
+        .ok_or(tz::Error::TzParseFail(input))
+}
+
+/// Suggests zones for a partially typed timezone, best matches first.
+///
+/// Exact names, cities and abbreviations rank above names that start with the input, which
+/// rank above names that merely contain it.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use chrono_tz::Tz;
+/// use walzecore::tz::zone::{suggest, MAX_SUGGESTIONS};
+///
+/// let now = Utc.with_ymd_and_hms(2026, 10, 18, 12, 0, 0).unwrap();
+/// assert_eq!(suggest("berl", now)[0], Tz::Europe__Berlin);
+/// assert_eq!(suggest("york", now)[0], Tz::America__New_York);
+/// assert_eq!(suggest("PST", now)[0], Tz::America__Los_Angeles);
+/// assert_eq!(suggest("utc+5:30", now)[0], Tz::Asia__Kolkata);
+/// assert!(suggest("a", now).len() <= MAX_SUGGESTIONS);
+/// assert!(!suggest("", now).is_empty());
+/// ```
+pub fn suggest(partial: &str, now: DateTime<Utc>) -> Vec<Tz> {
+    let query = normalize(partial);
+    if query.is_empty() {
+        let mut popular: Vec<Tz> = PREFERRED.iter().map(|(_, tz)| *tz).collect();
+        popular.dedup();
+        popular.insert(0, Tz::UTC);
+        popular.truncate(MAX_SUGGESTIONS);
+        return popular;
+    }
+
+    let resolved = resolve(partial, now).ok();
+    let offset = parse_offset(&query);
+    let abbreviation = query.to_uppercase();
+    let abbreviations = abbreviated(&abbreviation, now);
+
+    let mut ranked: Vec<(u8, Tz)> = TZ_VARIANTS
+        .iter()
+        .filter_map(|tz| {
+            let name = normalize(tz.name());
+            let city = city(tz.name());
+            let rank = if resolved == Some(*tz) {
+                0
+            } else if name == query
+                || city == query
+                || abbreviations.iter().any(|(other, _)| other == tz)
+                || offset.is_some_and(|offset| offset_at(*tz, now) == offset)
+            {
+                1
+            } else if city.starts_with(&query) {
+                2
+            } else if name.starts_with(&query) {
+                3
+            } else if name.split(['/', ' ']).any(|word| word.starts_with(&query)) {
+                4
+            } else if name.contains(&query) {
+                5
+            } else {
+                return None;
+            };
+            Some((rank, *tz))
+        })
+        .collect();
+    ranked.sort_by_key(|(rank, tz)| (*rank, Reverse(is_canonical(*tz)), tz.name().len()));
+    ranked
+        .into_iter()
+        .map(|(_, tz)| tz)
+        .take(MAX_SUGGESTIONS)
+        .collect()
+}
+
+/// Returns the offset from UTC a zone has at `now`.
+pub fn offset_at(tz: Tz, now: DateTime<Utc>) -> FixedOffset {
+    tz.offset_from_utc_datetime(&now.naive_utc()).fix()
+}
+
+/// Lowercases a name and reads underscores as spaces.
+fn normalize(name: &str) -> String {
+    name.trim().to_lowercase().replace('_', " ")
+}
+
+/// Returns the city of a zone name, the part after the last `/`.
+fn city(name: &str) -> String {
+    normalize(name.rsplit('/').next().unwrap_or(name))
+}
+
+/// Zones picked for an abbreviation or an offset.
+fn is_known(tz: Tz) -> bool {
+    FRACTIONAL.contains(&tz) || PREFERRED.iter().any(|(_, other)| *other == tz)
+}
+
+/// Zones named for a region, not aliases in `Etc/` or outdated ones like `US/Eastern`.
+fn is_canonical(tz: Tz) -> bool {
+    let name = tz.name();
+    name.contains('/')
+        && !["Etc/", "US/", "Canada/", "Brazil/", "Mexico/", "Chile/"]
+            .iter()
+            .any(|prefix| name.starts_with(prefix))
+}
+
+/// Parses an offset from UTC, `+5:30` or `utc-3`.
+fn parse_offset(query: &str) -> Option<FixedOffset> {
+    let caps = OFFSET_REGEX.captures(query)?;
+    let hours: i32 = caps[2].parse().ok()?;
+    let minutes: i32 = caps.get(3).map_or(Ok(0), |m| m.as_str().parse()).ok()?;
+    let seconds = (hours * 60 + minutes) * 60;
+    match &caps[1] {
+        "-" => FixedOffset::west_opt(seconds),
+        _ => FixedOffset::east_opt(seconds),
+    }
+}
+
+/// Finds a zone that keeps the offset all year.
+fn fixed_zone(offset: FixedOffset, now: DateTime<Utc>) -> Option<Tz> {
+    let seconds = offset.local_minus_utc();
+    if seconds == 0 {
+        return Some(Tz::UTC);
+    }
+    // Etc zones have their sign inverted, Etc/GMT-5 is five hours ahead of UTC
+    if seconds % 3600 == 0 {
+        let name = format!("Etc/GMT{:+}", -seconds / 3600);
+        if let Ok(tz) = name.parse() {
+            return Some(tz);
+        }
+    }
+    let half_year = now + Duration::days(182);
+    FRACTIONAL
+        .iter()
+        .chain(TZ_VARIANTS.iter())
+        .find(|tz| offset_at(**tz, now) == offset && offset_at(**tz, half_year) == offset)
+        .copied()
+}
+
+/// Returns the zones using an abbreviation around `now`, with the offset they have with it.
+fn abbreviated(abbreviation: &str, now: DateTime<Utc>) -> Vec<(Tz, FixedOffset)> {
+    if abbreviation.len() < 2 || !abbreviation.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Vec::new();
+    }
+    let seasons = [now, now + Duration::days(182)];
+    let mut zones: Vec<(Tz, FixedOffset)> = TZ_VARIANTS
+        .iter()
+        .filter_map(|tz| {
+            seasons.iter().find_map(|at| {
+                let offset = tz.offset_from_utc_datetime(&at.naive_utc());
+                (offset.abbreviation() == abbreviation).then(|| (*tz, offset.fix()))
+            })
+        })
+        .collect();
+    zones.sort_by_key(|(tz, _)| Reverse(is_canonical(*tz)));
+    zones
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn winter() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 15, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn summer_abbreviations_resolve_in_winter() {
+        assert_eq!(resolve("CEST", winter()).unwrap(), Tz::Europe__Berlin);
+        assert_eq!(resolve("BST", winter()).unwrap(), Tz::Europe__London);
+        assert_eq!(resolve("EDT", winter()).unwrap(), Tz::America__New_York);
+        // not a preferred abbreviation, found in the summer half of the year
+        assert_eq!(resolve("NDT", winter()).unwrap(), Tz::America__St_Johns);
+    }
+
+    #[test]
+    fn southern_summer_abbreviations_resolve_in_january() {
+        assert_eq!(resolve("AEDT", winter()).unwrap(), Tz::Australia__Sydney);
+        assert_eq!(resolve("NZDT", winter()).unwrap(), Tz::Pacific__Auckland);
+    }
+
+    #[test]
+    fn ambiguous_abbreviations_list_the_zones_using_them_in_winter() {
+        // Israel uses IST only in winter
+        assert_eq!(
+            resolve("IST", winter()).unwrap_err().to_string(),
+            "\"IST\" could mean Europe/Dublin (+01:00), Asia/Jerusalem (+02:00), \
+             Asia/Kolkata (+05:30), give the name of one"
+        );
+    }
+
+    #[test]
+    fn offsets_only_resolve_to_zones_keeping_them_all_year() {
+        assert_eq!(resolve("UTC+1", winter()).unwrap(), Tz::Etc__GMTMinus1);
+        assert_eq!(resolve("UTC+5:45", winter()).unwrap(), Tz::Asia__Kathmandu);
+        // Newfoundland is at -3:30 only in winter
+        assert!(resolve("UTC-3:30", winter()).is_err());
+    }
+}